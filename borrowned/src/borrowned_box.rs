@@ -106,7 +106,7 @@ impl<'b, T: ?Sized> AsMut<T> for BorrownedBox<'b, T> {
     }
 }
 
-impl<'b, T: Clone + ?Sized> Clone for BorrownedBox<'b, T> {
+impl<'b, T: Clone> Clone for BorrownedBox<'b, T> {
     fn clone(&self) -> Self {
         match self {
             BorrownedBox::Owned(owned) => BorrownedBox::Owned(owned.clone()),
@@ -151,7 +151,7 @@ impl<'b, T: Hash + ?Sized> Hash for BorrownedBox<'b, T> {
     }
 }
 
-impl<'b, T: Default + ?Sized> Default for BorrownedBox<'b, T> {
+impl<'b, T: Default> Default for BorrownedBox<'b, T> {
     fn default() -> Self {
         Self::Owned(Box::default())
     }
@@ -0,0 +1,11 @@
+//! Collection types that build on the ones in [`std::collections`].
+
+mod bi_map;
+mod counter_map;
+mod default_map;
+mod multi_map;
+
+pub use bi_map::*;
+pub use counter_map::*;
+pub use default_map::*;
+pub use multi_map::*;
@@ -0,0 +1,272 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/// A one-to-one map between left values and right values, which can be looked up in either
+/// direction.
+///
+/// Every left value is associated with exactly one right value and vice versa, so inserting a
+/// pair removes any existing pairs that share either of its values; see
+/// [`insert`](BiMap::insert). Both values are stored twice, once for each direction, which is
+/// why they need to be [`Clone`].
+///
+/// # Example
+/// ```
+/// # use ilyvion_util::collections::BiMap;
+/// let mut ids = BiMap::new();
+/// ids.insert(1, String::from("alice"));
+/// ids.insert(2, String::from("bob"));
+///
+/// assert_eq!(ids.get_by_left(&1).map(String::as_str), Some("alice"));
+/// assert_eq!(ids.get_by_right("bob"), Some(&2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BiMap<L, R> {
+    left_to_right: HashMap<L, R>,
+    right_to_left: HashMap<R, L>,
+}
+
+/// The pairs that were removed from a [`BiMap`] to make room for a newly inserted pair.
+///
+/// This `enum` is returned by the [`insert`](BiMap::insert) method on [`BiMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Overwritten<L, R> {
+    /// No pairs were removed.
+    Neither,
+    /// The exact same pair was already in the map.
+    Pair(L, R),
+    /// A pair with the same left value was removed.
+    Left(L, R),
+    /// A pair with the same right value was removed.
+    Right(L, R),
+    /// Both a pair with the same left value and a (different) pair with the same right value
+    /// were removed, in that order.
+    Both((L, R), (L, R)),
+}
+
+impl<L, R> BiMap<L, R> {
+    /// Creates an empty `BiMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            left_to_right: HashMap::new(),
+            right_to_left: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of pairs in the map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.left_to_right.len()
+    }
+
+    /// Returns `true` if the map contains no pairs.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.left_to_right.is_empty()
+    }
+
+    /// Removes all pairs from the map.
+    pub fn clear(&mut self) {
+        self.left_to_right.clear();
+        self.right_to_left.clear();
+    }
+
+    /// Returns an iterator over the pairs of the map, in arbitrary order.
+    #[must_use]
+    pub fn iter(&self) -> hash_map::Iter<'_, L, R> {
+        self.left_to_right.iter()
+    }
+}
+
+impl<L, R> BiMap<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    /// Inserts the pair `left`, `right`, first removing any pair whose left value is `left` and
+    /// any pair whose right value is `right`, so that the map stays one-to-one. Returns the
+    /// removed pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ilyvion_util::collections::{BiMap, Overwritten};
+    /// let mut map = BiMap::new();
+    /// assert_eq!(map.insert('a', 1), Overwritten::Neither);
+    /// assert_eq!(map.insert('b', 2), Overwritten::Neither);
+    ///
+    /// assert_eq!(map.insert('a', 2), Overwritten::Both(('a', 1), ('b', 2)));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn insert(&mut self, left: L, right: R) -> Overwritten<L, R> {
+        let by_left = self.remove_by_left(&left);
+        let by_right = self.remove_by_right(&right);
+        let same_pair = matches!(&by_left, Some((_, r)) if *r == right);
+        self.insert_unchecked(left, right);
+
+        match (by_left, by_right) {
+            (None, None) => Overwritten::Neither,
+            (Some((l, r)), None) if same_pair => Overwritten::Pair(l, r),
+            (Some((l, r)), None) => Overwritten::Left(l, r),
+            (None, Some((l, r))) => Overwritten::Right(l, r),
+            (Some(by_left), Some(by_right)) => Overwritten::Both(by_left, by_right),
+        }
+    }
+
+    /// Inserts the pair `left`, `right` only if neither value is already in the map. Otherwise,
+    /// the map is left unchanged and the pair is given back as the error.
+    ///
+    /// # Errors
+    ///
+    /// If the map already contains a pair with either the left or the right value.
+    pub fn try_insert(&mut self, left: L, right: R) -> Result<(), (L, R)> {
+        if self.contains_left(&left) || self.contains_right(&right) {
+            return Err((left, right));
+        }
+
+        self.insert_unchecked(left, right);
+        Ok(())
+    }
+
+    fn insert_unchecked(&mut self, left: L, right: R) {
+        self.left_to_right.insert(left.clone(), right.clone());
+        self.right_to_left.insert(right, left);
+    }
+
+    /// Returns the right value associated with `left`.
+    #[must_use]
+    pub fn get_by_left<Q>(&self, left: &Q) -> Option<&R>
+    where
+        L: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.left_to_right.get(left)
+    }
+
+    /// Returns the left value associated with `right`.
+    #[must_use]
+    pub fn get_by_right<Q>(&self, right: &Q) -> Option<&L>
+    where
+        R: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.right_to_left.get(right)
+    }
+
+    /// Returns `true` if the map contains a pair with the left value `left`.
+    #[must_use]
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        L: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.left_to_right.contains_key(left)
+    }
+
+    /// Returns `true` if the map contains a pair with the right value `right`.
+    #[must_use]
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        R: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.right_to_left.contains_key(right)
+    }
+
+    /// Removes the pair with the left value `left`, returning it if it was in the map.
+    pub fn remove_by_left<Q>(&mut self, left: &Q) -> Option<(L, R)>
+    where
+        L: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let (left, right) = self.left_to_right.remove_entry(left)?;
+        self.right_to_left.remove(&right);
+        Some((left, right))
+    }
+
+    /// Removes the pair with the right value `right`, returning it if it was in the map.
+    pub fn remove_by_right<Q>(&mut self, right: &Q) -> Option<(L, R)>
+    where
+        R: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let (right, left) = self.right_to_left.remove_entry(right)?;
+        self.left_to_right.remove(&left);
+        Some((left, right))
+    }
+}
+
+impl<L, R> Default for BiMap<L, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inserts the pairs in order using [`BiMap::insert`], so later pairs replace earlier ones they
+/// share a value with.
+impl<L, R> Extend<(L, R)> for BiMap<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    fn extend<T: IntoIterator<Item = (L, R)>>(&mut self, iter: T) {
+        for (left, right) in iter {
+            self.insert(left, right);
+        }
+    }
+}
+
+/// Inserts the pairs in order using [`BiMap::insert`], so later pairs replace earlier ones they
+/// share a value with.
+impl<L, R> FromIterator<(L, R)> for BiMap<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    fn from_iter<T: IntoIterator<Item = (L, R)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, L, R> IntoIterator for &'a BiMap<L, R> {
+    type Item = (&'a L, &'a R);
+    type IntoIter = hash_map::Iter<'a, L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_reports_what_it_overwrote() {
+        let mut map = BiMap::new();
+        map.insert(1, 'a');
+        assert_eq!(map.insert(1, 'a'), Overwritten::Pair(1, 'a'));
+        assert_eq!(map.insert(1, 'b'), Overwritten::Left(1, 'a'));
+        assert_eq!(map.insert(2, 'b'), Overwritten::Right(1, 'b'));
+        assert_eq!(map.get_by_right(&'b'), Some(&2));
+        assert_eq!(map.get_by_left(&1), None);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn both_directions_stay_in_sync() {
+        let mut map: BiMap<_, _> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+        assert_eq!(map.try_insert(3, 'a'), Err((3, 'a')));
+        assert_eq!(map.try_insert(3, 'c'), Ok(()));
+
+        assert_eq!(map.remove_by_right(&'b'), Some((2, 'b')));
+        assert!(!map.contains_left(&2));
+        assert_eq!(map.remove_by_left(&1), Some((1, 'a')));
+        assert!(!map.contains_right(&'a'));
+        assert_eq!(map.len(), 1);
+    }
+}
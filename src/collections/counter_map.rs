@@ -0,0 +1,247 @@
+use crate::iterator_extensions::IteratorExtensions;
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::{Add, AddAssign, Index, Sub, SubAssign};
+
+/// A map that counts how many times each key has occurred, like Python's `Counter`.
+///
+/// Keys that haven't occurred have a count of zero, and keys whose count drops to zero are
+/// removed, so that only keys with a positive count are ever stored.
+///
+/// # Example
+/// ```
+/// # use ilyvion_util::collections::CounterMap;
+/// let letters: CounterMap<_> = "abracadabra".chars().collect();
+///
+/// assert_eq!(letters[&'r'], 2);
+/// assert_eq!(letters[&'z'], 0);
+/// assert_eq!(letters.most_common(1), [(&'a', 5)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterMap<K: Eq + Hash> {
+    counts: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash> CounterMap<K> {
+    /// Creates an empty `CounterMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of distinct keys with a positive count.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns `true` if no key has a positive count.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns the sum of all the counts.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns the count of `key`, which is zero if it hasn't occurred.
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Increments the count of `key` by one, returning the new count.
+    pub fn increment(&mut self, key: K) -> usize {
+        self.increment_by(key, 1)
+    }
+
+    /// Increments the count of `key` by `n`, returning the new count.
+    pub fn increment_by(&mut self, key: K, n: usize) -> usize {
+        if n == 0 {
+            return self.get(&key);
+        }
+
+        let count = self.counts.entry(key).or_insert(0);
+        *count += n;
+        *count
+    }
+
+    /// Decrements the count of `key` by one, unless it's already zero, returning the new count.
+    pub fn decrement_saturating<Q>(&mut self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.decrement_by_saturating(key, 1)
+    }
+
+    /// Decrements the count of `key` by `n`, stopping at zero, returning the new count.
+    pub fn decrement_by_saturating<Q>(&mut self, key: &Q, n: usize) -> usize
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let count = match self.counts.get_mut(key) {
+            Some(count) => count,
+            None => return 0,
+        };
+
+        *count = count.saturating_sub(n);
+        if *count > 0 {
+            return *count;
+        }
+        self.counts.remove(key);
+        0
+    }
+
+    /// Removes `key`, returning the count it had.
+    pub fn remove<Q>(&mut self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.counts.remove(key).unwrap_or(0)
+    }
+
+    /// Returns the `n` keys with the highest counts along with their counts, from the highest to
+    /// the lowest count. Keys with the same count are ordered arbitrarily.
+    #[must_use]
+    pub fn most_common(&self, n: usize) -> Vec<(&K, usize)> {
+        self.iter()
+            .map(|(key, &count)| (key, count))
+            .top_k_by_key(n, |&(_, count)| count)
+    }
+
+    /// Returns an iterator over the keys and their counts, in arbitrary order.
+    #[must_use]
+    pub fn iter(&self) -> hash_map::Iter<'_, K, usize> {
+        self.counts.iter()
+    }
+}
+
+impl<K: Eq + Hash> Default for CounterMap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the count of a key, which is zero if it hasn't occurred.
+impl<K, Q> Index<&Q> for CounterMap<K>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: ?Sized + Eq + Hash,
+{
+    type Output = usize;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.counts.get(key).unwrap_or(&0)
+    }
+}
+
+impl<K: Eq + Hash> Extend<K> for CounterMap<K> {
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        for key in iter {
+            self.increment(key);
+        }
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for CounterMap<K> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<'a, K: Eq + Hash> IntoIterator for &'a CounterMap<K> {
+    type Item = (&'a K, &'a usize);
+    type IntoIter = hash_map::Iter<'a, K, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Adds the counts of `rhs` to the counts of `self`.
+impl<K: Eq + Hash> AddAssign for CounterMap<K> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (key, count) in rhs.counts {
+            self.increment_by(key, count);
+        }
+    }
+}
+
+/// Adds the counts of `rhs` to the counts of `self`.
+impl<K: Eq + Hash> Add for CounterMap<K> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// Subtracts the counts of `rhs` from the counts of `self`, removing the keys whose counts would
+/// drop to zero or below.
+impl<K: Eq + Hash> SubAssign for CounterMap<K> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (key, count) in rhs.counts {
+            self.decrement_by_saturating(&key, count);
+        }
+    }
+}
+
+/// Subtracts the counts of `rhs` from the counts of `self`, removing the keys whose counts would
+/// drop to zero or below.
+impl<K: Eq + Hash> Sub for CounterMap<K> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_counts_are_never_stored() {
+        let mut counter = CounterMap::new();
+        assert_eq!(counter.increment_by("a", 0), 0);
+        assert!(counter.is_empty());
+
+        assert_eq!(counter.increment("a"), 1);
+        assert_eq!(counter.decrement_saturating("a"), 0);
+        assert_eq!(counter.decrement_saturating("a"), 0);
+        assert!(counter.is_empty());
+    }
+
+    #[test]
+    fn arithmetic_between_counters() {
+        let first: CounterMap<_> = "aab".chars().collect();
+        let second: CounterMap<_> = "abbc".chars().collect();
+
+        let sum = first.clone() + second.clone();
+        assert_eq!((sum[&'a'], sum[&'b'], sum[&'c']), (3, 3, 1));
+        assert_eq!(sum.total(), 7);
+
+        let difference = first - second;
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference.get(&'a'), 1);
+        assert_eq!(difference.most_common(5), [(&'a', 1)]);
+    }
+}
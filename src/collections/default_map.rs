@@ -0,0 +1,190 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+/// A map that treats missing keys as if they mapped to `V::default()`, inserting that value
+/// whenever a missing key is accessed mutably.
+///
+/// This removes the need for `map.entry(key).or_default()` when accumulating values.
+///
+/// # Example
+/// ```
+/// # use ilyvion_util::collections::DefaultMap;
+/// let mut lengths: DefaultMap<usize, Vec<&str>> = DefaultMap::new();
+/// for word in "the quick brown fox jumps".split(' ') {
+///     lengths[word.len()].push(word);
+/// }
+///
+/// assert_eq!(lengths[5], ["quick", "brown", "jumps"]);
+/// assert!(lengths[4].is_empty());
+/// assert_eq!(lengths.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DefaultMap<K, V> {
+    map: HashMap<K, V>,
+    default: V,
+}
+
+impl<K, V: Default> DefaultMap<K, V> {
+    /// Creates an empty `DefaultMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            default: V::default(),
+        }
+    }
+}
+
+impl<K, V> DefaultMap<K, V> {
+    /// Returns the number of keys that have been inserted into the map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if no keys have been inserted into the map.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over the inserted keys and their values, in arbitrary order.
+    #[must_use]
+    pub fn iter(&self) -> hash_map::Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    /// Returns the underlying [`HashMap`].
+    #[must_use]
+    pub fn into_inner(self) -> HashMap<K, V> {
+        self.map
+    }
+}
+
+impl<K: Eq + Hash, V: Default> DefaultMap<K, V> {
+    /// Returns the value of `key`, or the default value if the key hasn't been inserted. The
+    /// key is *not* inserted in the latter case.
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> &V
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.map.get(key).unwrap_or(&self.default)
+    }
+
+    /// Returns a mutable reference to the value of `key`, first inserting the default value if
+    /// the key hasn't been inserted.
+    pub fn get_or_default_mut(&mut self, key: K) -> &mut V {
+        self.map.entry(key).or_default()
+    }
+
+    /// Inserts `value` for `key`, returning the previous value if there was one.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(key, value)
+    }
+
+    /// Returns `true` if `key` has been inserted into the map.
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Removes `key` from the map, returning its value if it had been inserted.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.map.remove(key)
+    }
+}
+
+impl<K, V: Default> Default for DefaultMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V: Default> From<HashMap<K, V>> for DefaultMap<K, V> {
+    fn from(map: HashMap<K, V>) -> Self {
+        Self {
+            map,
+            default: V::default(),
+        }
+    }
+}
+
+/// Returns the value of a key, or the default value if the key hasn't been inserted.
+impl<K: Eq + Hash, V: Default> Index<K> for DefaultMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: K) -> &Self::Output {
+        self.get(&key)
+    }
+}
+
+/// Returns the value of a key, first inserting the default value if the key hasn't been
+/// inserted.
+impl<K: Eq + Hash, V: Default> IndexMut<K> for DefaultMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        self.get_or_default_mut(key)
+    }
+}
+
+impl<K: Eq + Hash, V: Default> Extend<(K, V)> for DefaultMap<K, V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.map.extend(iter);
+    }
+}
+
+impl<K: Eq + Hash, V: Default> FromIterator<(K, V)> for DefaultMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self::from(iter.into_iter().collect::<HashMap<_, _>>())
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a DefaultMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> IntoIterator for DefaultMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reading_never_inserts() {
+        let mut map: DefaultMap<&str, i32> = DefaultMap::new();
+        assert_eq!(map["missing"], 0);
+        assert_eq!(*map.get("missing"), 0);
+        assert!(map.is_empty());
+
+        map["hits"] += 2;
+        *map.get_or_default_mut("hits") += 1;
+        assert_eq!(map["hits"], 3);
+        assert!(map.contains_key("hits"));
+        assert_eq!(map.remove("hits"), Some(3));
+        assert_eq!(map["hits"], 0);
+    }
+}
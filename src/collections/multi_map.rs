@@ -0,0 +1,264 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::iter::{FromIterator, FusedIterator};
+use std::slice;
+
+/// A map that associates every key with a list of values, in the order they were inserted.
+///
+/// This is a thin wrapper around a `HashMap<K, Vec<V>>` that takes care of creating and removing
+/// the lists as values are inserted and removed, so that no key ever maps to an empty list.
+///
+/// # Example
+/// ```
+/// # use ilyvion_util::collections::MultiMap;
+/// let mut authors = MultiMap::new();
+/// authors.insert("Good Omens", "Terry Pratchett");
+/// authors.insert("Good Omens", "Neil Gaiman");
+/// authors.insert("Mort", "Terry Pratchett");
+///
+/// assert_eq!(authors.get("Good Omens"), ["Terry Pratchett", "Neil Gaiman"]);
+/// assert_eq!(authors.len(), 3);
+/// assert_eq!(authors.key_count(), 2);
+///
+/// assert!(authors.remove_value("Good Omens", &"Neil Gaiman"));
+/// assert_eq!(authors.get("Good Omens"), ["Terry Pratchett"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiMap<K, V> {
+    map: HashMap<K, Vec<V>>,
+    len: usize,
+}
+
+impl<K, V> MultiMap<K, V> {
+    /// Creates an empty `MultiMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values in the map, counting every value of every key.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of distinct keys in the map.
+    #[must_use]
+    pub fn key_count(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all keys and values from the map.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the distinct keys of the map, in arbitrary order.
+    #[must_use]
+    pub fn keys(&self) -> hash_map::Keys<'_, K, Vec<V>> {
+        self.map.keys()
+    }
+
+    /// Returns an iterator over every key along with all of its values, in arbitrary order.
+    #[must_use]
+    pub fn groups(&self) -> hash_map::Iter<'_, K, Vec<V>> {
+        self.map.iter()
+    }
+
+    /// Returns an iterator over every key-value pair of the map, with a key repeated once for
+    /// each of its values. The keys are visited in arbitrary order, but the values of each key
+    /// in the order they were inserted.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            groups: self.map.iter(),
+            current: None,
+            len: self.len,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> MultiMap<K, V> {
+    /// Adds `value` to the end of the values of `key`.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.map.entry(key).or_default().push(value);
+        self.len += 1;
+    }
+
+    /// Returns the values of `key`, which is empty if the map doesn't contain the key.
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> &[V]
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.map.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns a mutable slice of the values of `key`, which is empty if the map doesn't contain
+    /// the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> &mut [V]
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.map.get_mut(key).map_or(&mut [], Vec::as_mut_slice)
+    }
+
+    /// Returns `true` if the map contains at least one value for `key`.
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Removes `key` from the map, returning all of its values if it was in the map.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Vec<V>>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let values = self.map.remove(key)?;
+        self.len -= values.len();
+        Some(values)
+    }
+
+    /// Removes the first value of `key` that is equal to `value`, removing the key as well if it
+    /// has no values left. Returns whether a value was removed.
+    pub fn remove_value<Q>(&mut self, key: &Q, value: &V) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+        V: PartialEq,
+    {
+        let values = match self.map.get_mut(key) {
+            Some(values) => values,
+            None => return false,
+        };
+        let index = match values.iter().position(|v| v == value) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        values.remove(index);
+        if values.is_empty() {
+            self.map.remove(key);
+        }
+        self.len -= 1;
+        true
+    }
+}
+
+impl<K, V> Default for MultiMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash, V> Extend<(K, V)> for MultiMap<K, V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> FromIterator<(K, V)> for MultiMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a MultiMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the key-value pairs of a [`MultiMap`].
+///
+/// This `struct` is created by the [`iter`](MultiMap::iter) method on [`MultiMap`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, V> {
+    groups: hash_map::Iter<'a, K, Vec<V>>,
+    current: Option<(&'a K, slice::Iter<'a, V>)>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, values)) = &mut self.current {
+                if let Some(value) = values.next() {
+                    self.len -= 1;
+                    return Some((*key, value));
+                }
+            }
+
+            let (key, values) = self.groups.next()?;
+            self.current = Some((key, values.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_lengths_in_sync() {
+        let mut map: MultiMap<_, _> = vec![(1, 'a'), (2, 'b'), (1, 'c')].into_iter().collect();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.iter().len(), 3);
+
+        assert!(!map.remove_value(&1, &'x'));
+        assert!(!map.remove_value(&3, &'a'));
+        assert!(map.remove_value(&2, &'b'));
+        assert!(!map.contains_key(&2));
+        assert_eq!(map.key_count(), 1);
+
+        assert_eq!(map.remove(&1), Some(vec!['a', 'c']));
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), []);
+    }
+
+    #[test]
+    fn iterates_over_every_pair() {
+        let mut map = MultiMap::new();
+        map.extend(vec![("x", 1), ("y", 2), ("x", 3)]);
+        map.get_mut("x")[0] = 10;
+
+        let mut pairs: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        pairs.sort_unstable();
+        assert_eq!(pairs, [("x", 3), ("x", 10), ("y", 2)]);
+    }
+}
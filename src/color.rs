@@ -1,7 +1,26 @@
 //! Deals with conversions between color spaces
 
+mod fmt;
+mod parse;
+mod quantize;
+
+pub use self::fmt::*;
+pub use parse::*;
+pub use quantize::*;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "better-docs", doc(cfg(feature = "serde")))]
+pub mod hex;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Represents an RGB color
+///
+/// With the `serde` feature enabled, this type (de)serializes as a struct with `red`, `green` and
+/// `blue` fields. See the `hex` module if you'd rather have it represented as a hex string.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Rgb {
     /// The red component of the color in the range 0-255
     pub red: u8,
@@ -17,10 +36,243 @@ impl Rgb {
     pub fn new(red: u8, green: u8, blue: u8) -> Self {
         Self { red, green, blue }
     }
+
+    /// Creates a new `RGB` from a packed `0xRRGGBB` value. The most significant byte is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Rgb;
+    /// assert_eq!(Rgb::from_u32(0x10_2030), Rgb::new(0x10, 0x20, 0x30));
+    /// ```
+    #[must_use]
+    pub fn from_u32(rgb: u32) -> Self {
+        let [_, red, green, blue] = rgb.to_be_bytes();
+        Self::new(red, green, blue)
+    }
+
+    /// Returns this color packed as a `0xRRGGBB` value.
+    #[must_use]
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes([0, self.red, self.green, self.blue])
+    }
+
+    /// Returns this color along with its complement, i.e. the color on the opposite side of the
+    /// color wheel.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Rgb;
+    /// let red = Rgb::new(255, 0, 0);
+    /// assert_eq!(red.complementary(), [red, Rgb::new(0, 255, 255)]);
+    /// ```
+    #[must_use]
+    pub fn complementary(self) -> [Self; 2] {
+        self.hue_rotations([0., 180.])
+    }
+
+    /// Returns this color along with the two colors that are evenly spaced around the color wheel
+    /// from it, i.e. 120° apart.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Rgb;
+    /// let red = Rgb::new(255, 0, 0);
+    /// assert_eq!(
+    ///     red.triadic(),
+    ///     [red, Rgb::new(0, 255, 0), Rgb::new(0, 0, 255)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn triadic(self) -> [Self; 3] {
+        self.hue_rotations([0., 120., 240.])
+    }
+
+    /// Returns this color along with the three colors that are evenly spaced around the color
+    /// wheel from it, i.e. 90° apart.
+    #[must_use]
+    pub fn tetradic(self) -> [Self; 4] {
+        self.hue_rotations([0., 90., 180., 270.])
+    }
+
+    /// Returns `count` colors whose hues are spaced `step` degrees apart, centered on the hue of
+    /// this color. When `count` is odd, this color is the one in the middle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Rgb;
+    /// let green = Rgb::new(0, 255, 0);
+    /// assert_eq!(
+    ///     green.analogous(3, 60.),
+    ///     [Rgb::new(255, 255, 0), green, Rgb::new(0, 255, 255)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn analogous(self, count: usize, step: f64) -> Vec<Self> {
+        let hsv = Hsv::from(self);
+        let middle = (count as f64 - 1.) / 2.;
+        (0..count)
+            .map(|i| Self::from(hsv.rotate_hue((i as f64 - middle) * step)))
+            .collect()
+    }
+
+    fn hue_rotations<const N: usize>(self, degrees: [f64; N]) -> [Self; N] {
+        let hsv = Hsv::from(self);
+        degrees.map(|d| Self::from(hsv.rotate_hue(d)))
+    }
+}
+
+impl From<[u8; 3]> for Rgb {
+    fn from([red, green, blue]: [u8; 3]) -> Self {
+        Self::new(red, green, blue)
+    }
+}
+
+impl From<Rgb> for [u8; 3] {
+    fn from(rgb: Rgb) -> Self {
+        [rgb.red, rgb.green, rgb.blue]
+    }
+}
+
+/// Represents an RGB color with an alpha channel
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rgba {
+    /// The red component of the color in the range 0-255
+    pub red: u8,
+    /// The green component of the color in the range 0-255
+    pub green: u8,
+    /// The blue component of the color in the range 0-255
+    pub blue: u8,
+    /// The alpha component of the color in the range 0-255, where 0 is fully transparent
+    pub alpha: u8,
+}
+
+impl Rgba {
+    /// Creates a new `RGBA` with the given color values
+    #[must_use]
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Creates a new `RGBA` from a packed `0xRRGGBBAA` value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Rgba;
+    /// assert_eq!(Rgba::from_u32(0x1020_3040), Rgba::new(0x10, 0x20, 0x30, 0x40));
+    /// ```
+    #[must_use]
+    pub fn from_u32(rgba: u32) -> Self {
+        Self::from(rgba.to_be_bytes())
+    }
+
+    /// Returns this color packed as a `0xRRGGBBAA` value.
+    #[must_use]
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes(self.into())
+    }
+
+    /// Returns the color part of this `RGBA`, discarding the alpha channel.
+    #[must_use]
+    pub fn to_rgb(self) -> Rgb {
+        Rgb::new(self.red, self.green, self.blue)
+    }
+}
+
+impl From<Rgb> for Rgba {
+    /// Converts an [`Rgb`] into a fully opaque `Rgba`.
+    fn from(rgb: Rgb) -> Self {
+        Self::new(rgb.red, rgb.green, rgb.blue, u8::MAX)
+    }
+}
+
+impl From<[u8; 4]> for Rgba {
+    fn from([red, green, blue, alpha]: [u8; 4]) -> Self {
+        Self::new(red, green, blue, alpha)
+    }
+}
+
+impl From<Rgba> for [u8; 4] {
+    fn from(rgba: Rgba) -> Self {
+        [rgba.red, rgba.green, rgba.blue, rgba.alpha]
+    }
+}
+
+/// Represents an RGB color with 16 bits per channel, for when the 8 bits of [`Rgb`] aren't precise
+/// enough to avoid visible banding.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rgb16 {
+    /// The red component of the color in the range 0-65535
+    pub red: u16,
+    /// The green component of the color in the range 0-65535
+    pub green: u16,
+    /// The blue component of the color in the range 0-65535
+    pub blue: u16,
+}
+
+impl Rgb16 {
+    /// Creates a new `RGB16` with the given color values
+    #[must_use]
+    pub fn new(red: u16, green: u16, blue: u16) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Narrows this color to an [`Rgb`], rounding each component to the nearest 8-bit value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::{Rgb, Rgb16};
+    /// assert_eq!(Rgb16::new(0, 0x8080, 0xffff).to_rgb(), Rgb::new(0, 0x80, 0xff));
+    /// assert_eq!(Rgb16::new(0x00ff, 0x8000, 0xff00).to_rgb(), Rgb::new(1, 0x80, 0xfe));
+    /// ```
+    #[must_use]
+    pub fn to_rgb(self) -> Rgb {
+        // The result of the division is at most 255, so it always fits in a u8.
+        let narrow = |c: u16| ((u32::from(c) + 128) / 257) as u8;
+        Rgb::new(narrow(self.red), narrow(self.green), narrow(self.blue))
+    }
+}
+
+impl From<Rgb> for Rgb16 {
+    /// Widens an [`Rgb`] losslessly, i.e. such that `0xab` becomes `0xabab`.
+    fn from(rgb: Rgb) -> Self {
+        let widen = |c: u8| u16::from(c) * 257;
+        Self::new(widen(rgb.red), widen(rgb.green), widen(rgb.blue))
+    }
+}
+
+impl From<Rgb16> for Hsv {
+    fn from(rgb: Rgb16) -> Self {
+        Self::from_unit_rgb(
+            f64::from(rgb.red) / 65535.,
+            f64::from(rgb.green) / 65535.,
+            f64::from(rgb.blue) / 65535.,
+        )
+    }
+}
+
+impl From<Hsv> for Rgb16 {
+    fn from(hsv: Hsv) -> Self {
+        let (red, green, blue) = hsv.to_unit_rgb();
+
+        // The components are all in the range 0.0-1.0, so the results always fit in a u16.
+        let scale = |c: f64| (c * 65535.).round() as u16;
+        Self::new(scale(red), scale(green), scale(blue))
+    }
 }
 
 /// Represents a HSV color
+///
+/// With the `serde` feature enabled, this type (de)serializes as a struct with `hue`,
+/// `saturation` and `value` fields.
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Hsv {
     /// The hue component of the color in degrees [0-360)
     pub hue: f64,
@@ -40,14 +292,31 @@ impl Hsv {
             value,
         }
     }
+
+    /// Returns a copy of this color with its hue rotated by `degrees` around the color wheel,
+    /// wrapping around so the hue stays in the range [0-360).
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f64) -> Self {
+        Self {
+            hue: (self.hue + degrees).rem_euclid(360.),
+            ..self
+        }
+    }
 }
 
 impl From<Rgb> for Hsv {
     fn from(rgb: Rgb) -> Self {
-        let red = f64::from(rgb.red) / 255.;
-        let green = f64::from(rgb.green) / 255.;
-        let blue = f64::from(rgb.blue) / 255.;
+        Self::from_unit_rgb(
+            f64::from(rgb.red) / 255.,
+            f64::from(rgb.green) / 255.,
+            f64::from(rgb.blue) / 255.,
+        )
+    }
+}
 
+impl Hsv {
+    /// Converts RGB components in the range 0.0-1.0 to HSV.
+    fn from_unit_rgb(red: f64, green: f64, blue: f64) -> Self {
         let min = red.min(green).min(blue);
         let max = red.max(green).max(blue);
 
@@ -75,43 +344,94 @@ impl From<Rgb> for Hsv {
 
         Self::new(hue, saturation, value)
     }
-}
-
-impl From<Hsv> for Rgb {
-    fn from(hsv: Hsv) -> Self {
-        let saturation = (hsv.saturation * 255.) as u8;
-        let value = (hsv.value * 255.) as u8;
-        if saturation == 0 {
-            return Self::new(value, value, value);
-        }
 
-        let mut hue = hsv.hue;
-        if hue > 360.0 {
-            hue = 0.;
-        };
-        hue /= 60.0;
-        let i = hue as u32;
-        let ff = hue - f64::from(i);
-        let p = ((hsv.value * (1.0 - hsv.saturation)) * 255.) as u8;
-        let q = ((hsv.value * (1.0 - (hsv.saturation * ff))) * 255.) as u8;
-        let t = ((hsv.value * (1.0 - (hsv.saturation * (1.0 - ff)))) * 255.) as u8;
-
-        let (red, green, blue) = match i {
+    /// Converts this color to RGB components in the range 0.0-1.0.
+    fn to_unit_rgb(self) -> (f64, f64, f64) {
+        let saturation = self.saturation.clamp(0., 1.);
+        let value = self.value.clamp(0., 1.);
+        // `rem_euclid` can round up to exactly 360.0 for tiny negative hues.
+        let hue = self.hue.rem_euclid(360.);
+        let hue = if hue >= 360. { 0. } else { hue } / 60.;
+        let sector = hue.floor();
+        let ff = hue - sector;
+
+        let p = value * (1. - saturation);
+        let q = value * (1. - saturation * ff);
+        let t = value * (1. - saturation * (1. - ff));
+        match sector as u8 {
             0 => (value, t, p),
             1 => (q, value, p),
             2 => (p, value, t),
             3 => (p, q, value),
             4 => (t, p, value),
             _ => (value, p, q),
-        };
+        }
+    }
+}
 
-        Self::new(red, green, blue)
+impl From<Hsv> for Rgb {
+    fn from(hsv: Hsv) -> Self {
+        let (red, green, blue) = hsv.to_unit_rgb();
+
+        // The components are all in the range 0.0-1.0, so the results always fit in a u8.
+        let scale = |c: f64| (c * 255.) as u8;
+        Self::new(scale(red), scale(green), scale(blue))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Hsv, Rgb};
+    use super::{Hsv, Rgb, Rgb16, Rgba};
+
+    #[test]
+    fn rgb16_conversions() {
+        for c in 0..=255 {
+            let rgb = Rgb::new(c, 255 - c, c / 2);
+            assert_eq!(Rgb16::from(rgb).to_rgb(), rgb);
+        }
+
+        let orange = Rgb16::new(0xffff, 0x8000, 0);
+        let hsv = Hsv::from(orange);
+        assert!((hsv.hue - 30.).abs() < 0.001);
+        assert_eq!(Rgb16::from(hsv), orange);
+
+        assert_eq!(
+            Rgb16::from(Hsv::new(240., 0.5, 1.)),
+            Rgb16::new(0x8000, 0x8000, 0xffff)
+        );
+        assert_eq!(
+            Rgb16::from(Hsv::new(360., 1., 1.)),
+            Rgb16::new(0xffff, 0, 0)
+        );
+    }
+
+    #[test]
+    fn tiny_negative_hue_is_red() {
+        // -1e-20 is small enough that `rem_euclid(360.)` rounds up to exactly 360.0.
+        for hue in [-1e-10, -1e-20] {
+            let hsv = Hsv::new(hue, 1., 1.);
+
+            assert_eq!(Rgb16::from(hsv), Rgb16::new(0xffff, 0, 0));
+            assert_eq!(Rgb::from(hsv), Rgb::new(255, 0, 0));
+        }
+    }
+
+    #[test]
+    fn packed_and_array_conversions() {
+        let rgb = Rgb::new(0x12, 0x34, 0x56);
+
+        assert_eq!(rgb.to_u32(), 0x12_3456);
+        assert_eq!(Rgb::from_u32(0xff12_3456), rgb);
+        assert_eq!(<[u8; 3]>::from(rgb), [0x12, 0x34, 0x56]);
+        assert_eq!(Rgb::from([0x12, 0x34, 0x56]), rgb);
+
+        let rgba = Rgba::from(rgb);
+        assert_eq!(rgba, Rgba::new(0x12, 0x34, 0x56, 0xff));
+        assert_eq!(rgba.to_u32(), 0x1234_56ff);
+        assert_eq!(Rgba::from_u32(0x1234_5600).to_rgb(), rgb);
+        assert_eq!(<[u8; 4]>::from(rgba), [0x12, 0x34, 0x56, 0xff]);
+        assert_eq!(Rgba::from([0x12, 0x34, 0x56, 0xff]), rgba);
+    }
 
     #[test]
     fn validate_to_hsv() {
@@ -253,4 +573,30 @@ mod tests {
         assert_eq!(white_rgb, Rgb::new(255, 255, 255));
         assert_eq!(gray_rgb, Rgb::new(127, 127, 127));
     }
+
+    #[test]
+    fn harmonies() {
+        let red = Rgb::new(255, 0, 0);
+        let cyan = Rgb::new(0, 255, 255);
+
+        assert_eq!(red.complementary(), [red, cyan]);
+        assert_eq!(cyan.complementary(), [cyan, red]);
+        assert_eq!(
+            red.tetradic(),
+            [red, Rgb::new(127, 255, 0), cyan, Rgb::new(127, 0, 255)]
+        );
+        assert_eq!(
+            red.analogous(2, 60.),
+            [Rgb::new(255, 0, 127), Rgb::new(255, 127, 0)]
+        );
+        assert!(red.analogous(0, 30.).is_empty());
+    }
+
+    #[test]
+    fn rotate_hue_wraps_around() {
+        let hsv = Hsv::new(300., 1., 1.);
+
+        assert_eq!(hsv.rotate_hue(90.).hue, 30.);
+        assert_eq!(hsv.rotate_hue(-330.).hue, 330.);
+    }
 }
@@ -0,0 +1,110 @@
+use super::{Hsv, Rgb};
+use std::fmt;
+
+/// Helper struct for displaying an [`Rgb`] as `#rrggbb`. Created by [`Rgb::display_hex`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayHex(Rgb);
+
+/// Helper struct for displaying an [`Rgb`] as `rgb(r, g, b)`. Created by [`Rgb::display_rgb`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayRgb(Rgb);
+
+/// Helper struct for displaying an [`Hsv`] as `hsv(h, s, v)`. Created by [`Hsv::display_hsv`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayHsv(Hsv);
+
+impl Rgb {
+    /// Returns an object that implements [`Display`](fmt::Display) by writing this color as
+    /// `#rrggbb`. This is also what the `Display` implementation of `Rgb` itself does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Rgb;
+    /// assert_eq!(Rgb::new(255, 128, 0).display_hex().to_string(), "#ff8000");
+    /// ```
+    #[must_use]
+    pub fn display_hex(self) -> DisplayHex {
+        DisplayHex(self)
+    }
+
+    /// Returns an object that implements [`Display`](fmt::Display) by writing this color as
+    /// `rgb(r, g, b)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Rgb;
+    /// assert_eq!(Rgb::new(255, 128, 0).display_rgb().to_string(), "rgb(255, 128, 0)");
+    /// ```
+    #[must_use]
+    pub fn display_rgb(self) -> DisplayRgb {
+        DisplayRgb(self)
+    }
+}
+
+impl Hsv {
+    /// Returns an object that implements [`Display`](fmt::Display) by writing this color as
+    /// `hsv(h, s, v)`. This is also what the `Display` implementation of `Hsv` itself does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::color::Hsv;
+    /// assert_eq!(Hsv::new(120., 1., 0.5).display_hsv().to_string(), "hsv(120, 1, 0.5)");
+    /// ```
+    #[must_use]
+    pub fn display_hsv(self) -> DisplayHsv {
+        DisplayHsv(self)
+    }
+}
+
+impl fmt::Display for DisplayHex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Rgb { red, green, blue } = self.0;
+        write!(f, "#{:02x}{:02x}{:02x}", red, green, blue)
+    }
+}
+
+impl fmt::Display for DisplayRgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Rgb { red, green, blue } = self.0;
+        write!(f, "rgb({}, {}, {})", red, green, blue)
+    }
+}
+
+impl fmt::Display for DisplayHsv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Hsv {
+            hue,
+            saturation,
+            value,
+        } = self.0;
+        write!(f, "hsv({}, {}, {})", hue, saturation, value)
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_hex().fmt(f)
+    }
+}
+
+impl fmt::Display for Hsv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_hsv().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_output_parses_back() {
+        let rgb = Rgb::new(1, 22, 255);
+        let hsv = Hsv::new(300., 0.25, 0.125);
+
+        assert_eq!(rgb.to_string(), "#0116ff");
+        assert_eq!(rgb.to_string().parse(), Ok(rgb));
+        assert_eq!(rgb.display_rgb().to_string().parse(), Ok(rgb));
+        assert_eq!(hsv.to_string().parse(), Ok(hsv));
+    }
+}
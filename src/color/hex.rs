@@ -0,0 +1,60 @@
+//! Serializes and deserializes [`Rgb`] values as hex strings (i.e., `"#rrggbb"`) rather than as a
+//! struct with fields, which is usually what you want for colors in config files.
+//!
+//! # Examples
+//! ```
+//! # use ilyvion_util::color::Rgb;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Theme {
+//!     #[serde(with = "ilyvion_util::color::hex")]
+//!     background: Rgb,
+//! }
+//!
+//! let theme: Theme = serde_json::from_str(r##"{ "background": "#1e2a3b" }"##).unwrap();
+//! assert_eq!(theme.background, Rgb::new(0x1e, 0x2a, 0x3b));
+//! assert_eq!(
+//!     serde_json::to_string(&theme).unwrap(),
+//!     r##"{"background":"#1e2a3b"}"##
+//! );
+//! ```
+
+use super::parse::parse_hex_digits;
+use super::Rgb;
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+use std::fmt;
+
+/// Serializes an [`Rgb`] as a lowercase `"#rrggbb"` string.
+///
+/// # Errors
+///
+/// If the serializer fails to serialize the string.
+pub fn serialize<S: Serializer>(rgb: &Rgb, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&rgb.display_hex())
+}
+
+/// Deserializes an [`Rgb`] from a `"#rrggbb"` string. The leading `#` is optional and both upper-
+/// and lowercase hex digits are accepted.
+///
+/// # Errors
+///
+/// If the deserializer doesn't contain a string, or the string isn't a valid hex color.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgb, D::Error> {
+    deserializer.deserialize_str(HexVisitor)
+}
+
+struct HexVisitor;
+
+impl Visitor<'_> for HexVisitor {
+    type Value = Rgb;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a hex color string like \"#rrggbb\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        parse_hex_digits(v.strip_prefix('#').unwrap_or(v))
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
@@ -0,0 +1,203 @@
+use super::{Hsv, Rgb};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The error type returned when parsing an [`Rgb`] or [`Hsv`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The string didn't match any of the supported color syntaxes.
+    UnknownSyntax,
+    /// The string looked like a hex color, but wasn't `#` followed by six hex digits.
+    InvalidHex,
+    /// The functional syntax contained the given number of components rather than three.
+    ComponentCount(usize),
+    /// The component at the given (zero-based) position couldn't be parsed or was out of range.
+    InvalidComponent(usize),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSyntax => f.write_str(
+                "unknown color syntax; expected \"#rrggbb\", \"rgb(r, g, b)\" or \"hsv(h, s, v)\"",
+            ),
+            Self::InvalidHex => f.write_str("hex colors must be '#' followed by six hex digits"),
+            Self::ComponentCount(count) => {
+                write!(f, "expected 3 color components, found {}", count)
+            }
+            Self::InvalidComponent(index) => {
+                write!(
+                    f,
+                    "color component {} is invalid or out of range",
+                    index + 1
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// The color syntaxes recognized by the [`FromStr`] implementations, before any conversion
+/// between color spaces has taken place.
+#[allow(variant_size_differences)]
+enum Parsed {
+    Rgb(Rgb),
+    Hsv(Hsv),
+}
+
+impl FromStr for Parsed {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex_digits(hex)
+                .map(Self::Rgb)
+                .ok_or(ParseColorError::InvalidHex);
+        }
+
+        let open = s.find('(').ok_or(ParseColorError::UnknownSyntax)?;
+        let arguments = s[open + 1..]
+            .strip_suffix(')')
+            .ok_or(ParseColorError::UnknownSyntax)?;
+        let components: Vec<_> = arguments.split(',').map(str::trim).collect();
+        let name = s[..open].trim();
+        if !name.eq_ignore_ascii_case("rgb") && !name.eq_ignore_ascii_case("hsv") {
+            return Err(ParseColorError::UnknownSyntax);
+        }
+        if components.len() != 3 {
+            return Err(ParseColorError::ComponentCount(components.len()));
+        }
+
+        if name.eq_ignore_ascii_case("rgb") {
+            let component = |i: usize| {
+                components[i]
+                    .parse::<u8>()
+                    .map_err(|_| ParseColorError::InvalidComponent(i))
+            };
+            Ok(Self::Rgb(Rgb::new(
+                component(0)?,
+                component(1)?,
+                component(2)?,
+            )))
+        } else {
+            let component = |i: usize, valid: fn(f64) -> bool| {
+                components[i]
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|&c| valid(c))
+                    .ok_or(ParseColorError::InvalidComponent(i))
+            };
+            let unit = |c: f64| (0.0..=1.0).contains(&c);
+            Ok(Self::Hsv(Hsv::new(
+                component(0, f64::is_finite)?.rem_euclid(360.),
+                component(1, unit)?,
+                component(2, unit)?,
+            )))
+        }
+    }
+}
+
+/// Parses exactly six hex digits (without a leading `#`) into an [`Rgb`].
+pub(super) fn parse_hex_digits(hex: &str) -> Option<Rgb> {
+    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Rgb::new(component(0)?, component(2)?, component(4)?))
+}
+
+/// Parses an `Rgb` from any of the syntaxes `"#rrggbb"`, `"rgb(r, g, b)"` or `"hsv(h, s, v)"`,
+/// where `r`, `g` and `b` are integers in the range 0-255, `h` is a hue in degrees and `s` and `v`
+/// are in the range 0.0-1.0. Colors given as HSV are converted to RGB.
+///
+/// # Examples
+/// ```
+/// # use ilyvion_util::color::Rgb;
+/// assert_eq!("#ff8000".parse(), Ok(Rgb::new(255, 128, 0)));
+/// assert_eq!("rgb(255, 128, 0)".parse(), Ok(Rgb::new(255, 128, 0)));
+/// assert_eq!("hsv(120, 1.0, 1.0)".parse(), Ok(Rgb::new(0, 255, 0)));
+/// assert!("rgb(256, 0, 0)".parse::<Rgb>().is_err());
+/// ```
+impl FromStr for Rgb {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse()? {
+            Parsed::Rgb(rgb) => rgb,
+            Parsed::Hsv(hsv) => hsv.into(),
+        })
+    }
+}
+
+/// Parses an `Hsv` from any of the syntaxes accepted by the [`FromStr`] implementation of
+/// [`Rgb`]. Colors given as RGB are converted to HSV.
+///
+/// # Examples
+/// ```
+/// # use ilyvion_util::color::Hsv;
+/// assert_eq!("hsv(120, 1.0, 0.5)".parse(), Ok(Hsv::new(120., 1., 0.5)));
+/// assert_eq!("#0000ff".parse(), Ok(Hsv::new(240., 1., 1.)));
+/// assert!("hsv(120, 1.5, 0.5)".parse::<Hsv>().is_err());
+/// ```
+impl FromStr for Hsv {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse()? {
+            Parsed::Rgb(rgb) => rgb.into(),
+            Parsed::Hsv(hsv) => hsv,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_all_syntaxes() {
+        assert_eq!(" #AaBbCc ".parse(), Ok(Rgb::new(0xaa, 0xbb, 0xcc)));
+        assert_eq!("rgb(1,2,3)".parse(), Ok(Rgb::new(1, 2, 3)));
+        assert_eq!("RGB( 1 , 2 , 3 )".parse(), Ok(Rgb::new(1, 2, 3)));
+        assert_eq!("hsv(-120, 1, 1)".parse(), Ok(Hsv::new(240., 1., 1.)));
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!("red".parse::<Rgb>(), Err(ParseColorError::UnknownSyntax));
+        assert_eq!(
+            "hsl(1, 2, 3)".parse::<Rgb>(),
+            Err(ParseColorError::UnknownSyntax)
+        );
+        assert_eq!(
+            "rgb(1, 2, 3".parse::<Rgb>(),
+            Err(ParseColorError::UnknownSyntax)
+        );
+        assert_eq!("#abc".parse::<Rgb>(), Err(ParseColorError::InvalidHex));
+        assert_eq!("#abcdeg".parse::<Rgb>(), Err(ParseColorError::InvalidHex));
+        assert_eq!("#+f+f+f".parse::<Rgb>(), Err(ParseColorError::InvalidHex));
+        assert_eq!(
+            "rgb(1, 2)".parse::<Rgb>(),
+            Err(ParseColorError::ComponentCount(2))
+        );
+        assert_eq!(
+            "rgb(1, -2, 3)".parse::<Rgb>(),
+            Err(ParseColorError::InvalidComponent(1))
+        );
+        assert_eq!(
+            "hsv(NaN, 1, 1)".parse::<Hsv>(),
+            Err(ParseColorError::InvalidComponent(0))
+        );
+    }
+
+    #[test]
+    fn hex_digits_reject_signs() {
+        assert_eq!(parse_hex_digits("+fffff"), None);
+        assert_eq!(parse_hex_digits("+f+f+f"), None);
+        assert_eq!(parse_hex_digits("ffffff"), Some(Rgb::new(255, 255, 255)));
+    }
+}
@@ -0,0 +1,165 @@
+use super::Rgb;
+
+/// A reduced set of representative colors, as produced by [`quantize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<Rgb>,
+}
+
+impl Palette {
+    /// Returns the colors in this palette.
+    #[must_use]
+    pub fn colors(&self) -> &[Rgb] {
+        &self.colors
+    }
+
+    /// Returns the index of the palette color closest to `color`, or [`None`] if the palette is
+    /// empty.
+    #[must_use]
+    pub fn nearest_index(&self, color: Rgb) -> Option<usize> {
+        self.colors
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &c)| distance_squared(c, color))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the palette color closest to `color`, or [`None`] if the palette is empty.
+    #[must_use]
+    pub fn nearest(&self, color: Rgb) -> Option<Rgb> {
+        self.nearest_index(color).map(|i| self.colors[i])
+    }
+
+    /// Unwraps this `Palette`, returning the underlying [`Vec`].
+    #[must_use]
+    pub fn into_inner(self) -> Vec<Rgb> {
+        self.colors
+    }
+}
+
+/// Reduces `colors` to a [`Palette`] of at most `max_colors` representative colors using the
+/// median cut algorithm.
+///
+/// Fewer than `max_colors` colors are returned if `colors` doesn't contain enough distinct colors
+/// to fill the palette.
+///
+/// # Examples
+/// ```
+/// # use ilyvion_util::color::{quantize, Rgb};
+/// let pixels = [
+///     Rgb::new(250, 0, 0),
+///     Rgb::new(254, 4, 4),
+///     Rgb::new(0, 0, 250),
+///     Rgb::new(4, 4, 254),
+/// ];
+///
+/// let palette = quantize(&pixels, 2);
+/// assert_eq!(palette.colors().len(), 2);
+/// assert_eq!(palette.nearest(Rgb::new(200, 30, 30)), Some(Rgb::new(252, 2, 2)));
+/// assert_eq!(palette.nearest(Rgb::new(30, 30, 200)), Some(Rgb::new(2, 2, 252)));
+/// ```
+#[must_use]
+pub fn quantize(colors: &[Rgb], max_colors: usize) -> Palette {
+    if colors.is_empty() || max_colors == 0 {
+        return Palette { colors: vec![] };
+    }
+
+    let mut boxes = vec![colors.to_vec()];
+    while boxes.len() < max_colors {
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (i, widest_channel(b)))
+            .max_by_key(|&(_, (_, range))| range);
+        let (index, channel) = match widest {
+            Some((index, (channel, range))) if range > 0 => (index, channel),
+            _ => break,
+        };
+
+        let mut color_box = boxes.swap_remove(index);
+        color_box.sort_unstable_by_key(|&c| channel_value(c, channel));
+        let upper = color_box.split_off(color_box.len() / 2);
+        boxes.push(color_box);
+        boxes.push(upper);
+    }
+
+    Palette {
+        colors: boxes.iter().map(|b| average(b)).collect(),
+    }
+}
+
+fn channel_value(color: Rgb, channel: usize) -> u8 {
+    match channel {
+        0 => color.red,
+        1 => color.green,
+        _ => color.blue,
+    }
+}
+
+/// Returns the channel with the largest range of values in `colors`, along with that range.
+fn widest_channel(colors: &[Rgb]) -> (usize, u8) {
+    (0..3)
+        .map(|channel| {
+            let (min, max) = colors.iter().fold((u8::MAX, u8::MIN), |(min, max), &c| {
+                let value = channel_value(c, channel);
+                (min.min(value), max.max(value))
+            });
+            (channel, max.saturating_sub(min))
+        })
+        .max_by_key(|&(_, range)| range)
+        .unwrap()
+}
+
+fn average(colors: &[Rgb]) -> Rgb {
+    let (red, green, blue) = colors.iter().fold((0, 0, 0), |(r, g, b), c| {
+        (
+            r + u64::from(c.red),
+            g + u64::from(c.green),
+            b + u64::from(c.blue),
+        )
+    });
+    let len = colors.len() as u64;
+
+    // The average of u8 values always fits in a u8.
+    Rgb::new((red / len) as u8, (green / len) as u8, (blue / len) as u8)
+}
+
+fn distance_squared(a: Rgb, b: Rgb) -> u32 {
+    let channel = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    channel(a.red, b.red) + channel(a.green, b.green) + channel(a.blue, b.blue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_input_gives_empty_palette() {
+        let palette = quantize(&[], 4);
+
+        assert!(palette.colors().is_empty());
+        assert_eq!(palette.nearest(Rgb::new(1, 2, 3)), None);
+    }
+
+    #[test]
+    fn never_produces_more_colors_than_distinct_inputs() {
+        let colors = [Rgb::new(10, 20, 30); 16];
+
+        assert_eq!(quantize(&colors, 4).into_inner(), [Rgb::new(10, 20, 30)]);
+    }
+
+    #[test]
+    fn splits_along_the_widest_channel() {
+        let colors: Vec<_> = (0..=255).map(|g| Rgb::new(0, g, 0)).collect();
+
+        let palette = quantize(&colors, 4);
+        let mut greens: Vec<_> = palette.colors().iter().map(|c| c.green).collect();
+        greens.sort_unstable();
+
+        assert_eq!(greens, [31, 95, 159, 223]);
+        assert_eq!(
+            palette.nearest(Rgb::new(0, 100, 0)),
+            Some(Rgb::new(0, 95, 0))
+        );
+    }
+}
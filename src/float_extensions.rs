@@ -1,23 +1,595 @@
-//! Various [`f64`] and [`f32`] extensions
+//! Various float extensions. Most are implemented for every [`Float`] type, which includes [`f64`]
+//! and [`f32`]; the bit-level [`Ulps`] extensions are specific to [`f64`] and [`f32`].
+
+use num_traits::{clamp, Float};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
 
 /// Trait that provides a way to round floats to a specific amount of decimals
 pub trait RoundTo: Sized {
     /// Returns the nearest number to `self` rounded to `decimal`
     /// number of decimals. Half-way cases round away from 0.0.
     fn round_to(self, decimals: i32) -> Self;
+
+    /// Returns the largest number less than or equal to `self` with at most `decimals` number of
+    /// decimals. Values that are within a few units in the last place of such a number, like
+    /// `4.35` (which is actually stored as `4.3499999999999996…`), are treated as being that number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::RoundTo;
+    /// assert_eq!(1.239.floor_to(2), 1.23);
+    /// assert_eq!((-1.231).floor_to(2), -1.24);
+    /// assert_eq!(4.35.floor_to(2), 4.35);
+    /// assert_eq!(1234.5.floor_to(-2), 1200.0);
+    /// ```
+    fn floor_to(self, decimals: i32) -> Self;
+
+    /// Returns the smallest number greater than or equal to `self` with at most `decimals` number
+    /// of decimals. Values that are within a few units in the last place of such a number, like
+    /// `1.1` (which is actually stored as `1.1000000000000000888…`), are treated as being that
+    /// number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::RoundTo;
+    /// assert_eq!(1.231.ceil_to(2), 1.24);
+    /// assert_eq!((-1.239).ceil_to(2), -1.23);
+    /// assert_eq!(1.1.ceil_to(1), 1.1);
+    /// assert_eq!(1234.5.ceil_to(-2), 1300.0);
+    /// ```
+    fn ceil_to(self, decimals: i32) -> Self;
+
+    /// Returns the nearest number to `self` rounded to `decimals` number of decimals, with
+    /// half-way cases rounding to the number with an even last digit ("banker's rounding"). Unlike
+    /// rounding half-way cases away from zero, this doesn't bias sums of many rounded values.
+    /// Values that are within a few units in the last place of a half-way case, like `2.345`
+    /// (which is actually stored as `2.3449999999999999733…`), are treated as being half-way.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::RoundTo;
+    /// assert_eq!(2.345.round_to_even(2), 2.34);
+    /// assert_eq!(2.355.round_to_even(2), 2.36);
+    /// assert_eq!((-0.5).round_to_even(0), -0.0);
+    /// assert_eq!(2.3451.round_to_even(2), 2.35);
+    /// assert_eq!(250.0.round_to_even(-2), 200.0);
+    /// ```
+    fn round_to_even(self, decimals: i32) -> Self;
+}
+
+// Both the decimal value itself and scaling it by a power of ten can be off by half a unit in the
+// last place, so a value that is a few units away from a whole number is taken to be that number.
+const DIRECTED_ROUNDING_EPSILONS: f64 = 4.0;
+
+fn is_practically<T: Float>(value: T, whole: T) -> bool {
+    (value - whole).abs() <= value.abs() * T::epsilon() * cast(DIRECTED_ROUNDING_EPSILONS)
+}
+
+fn cast<T: Float>(value: f64) -> T {
+    T::from(value).expect("small constants are representable by every float type")
 }
 
-impl RoundTo for f64 {
+impl<T: Float> RoundTo for T {
     fn round_to(self, decimals: i32) -> Self {
-        let rounding_coefficient = (10.0_f64).powi(decimals);
+        let rounding_coefficient = cast::<T>(10.0).powi(decimals);
         (self * rounding_coefficient).round() / rounding_coefficient
     }
+
+    fn floor_to(self, decimals: i32) -> Self {
+        let rounding_coefficient = cast::<T>(10.0).powi(decimals);
+        let scaled = self * rounding_coefficient;
+        let nearest = scaled.round();
+        if is_practically(scaled, nearest) {
+            nearest / rounding_coefficient
+        } else {
+            scaled.floor() / rounding_coefficient
+        }
+    }
+
+    fn ceil_to(self, decimals: i32) -> Self {
+        let rounding_coefficient = cast::<T>(10.0).powi(decimals);
+        let scaled = self * rounding_coefficient;
+        let nearest = scaled.round();
+        if is_practically(scaled, nearest) {
+            nearest / rounding_coefficient
+        } else {
+            scaled.ceil() / rounding_coefficient
+        }
+    }
+
+    fn round_to_even(self, decimals: i32) -> Self {
+        let rounding_coefficient = cast::<T>(10.0).powi(decimals);
+        let scaled = self * rounding_coefficient;
+        let floor = scaled.floor();
+        if !is_practically(scaled, floor + cast(0.5)) {
+            return scaled.round() / rounding_coefficient;
+        }
+        let even = if (floor / cast(2.0)).fract().is_zero() {
+            floor
+        } else {
+            floor + T::one()
+        };
+        // Keep the sign when rounding e.g. -0.5 to zero, like the other rounding methods do
+        let even = if even.is_zero() && scaled.is_sign_negative() {
+            -even
+        } else {
+            even
+        };
+        even / rounding_coefficient
+    }
 }
 
-impl RoundTo for f32 {
-    fn round_to(self, decimals: i32) -> Self {
-        let rounding_coefficient = (10.0_f32).powi(decimals);
-        (self * rounding_coefficient).round() / rounding_coefficient
+/// Trait that provides a way to round floats to a specific number of significant figures
+pub trait RoundToSigFigs: Sized {
+    /// Returns `self` rounded to `sig_figs` significant figures, regardless of its order of
+    /// magnitude. Zero, infinities and `NaN` are returned unchanged. Unlike
+    /// [`RoundTo::round_to`], this rounds the exact binary value of `self`, so values that are
+    /// exactly half-way round to even; most decimal fractions aren't exactly representable,
+    /// however, so this rarely makes a difference in practice. Values close to the largest finite
+    /// value may round up to infinity.
+    ///
+    /// # Panics
+    ///
+    /// If `sig_figs` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::RoundToSigFigs;
+    /// assert_eq!(123_456.0.round_to_sig_figs(2), 120_000.0);
+    /// assert_eq!(0.000_123_456.round_to_sig_figs(3), 0.000_123);
+    /// assert_eq!((-9.99).round_to_sig_figs(2), -10.0);
+    /// assert_eq!(1.5e-310.round_to_sig_figs(1), 2e-310);
+    /// ```
+    fn round_to_sig_figs(self, sig_figs: u32) -> Self;
+}
+
+// Formatting in scientific notation rounds the exact value to the requested number of digits, at
+// any order of magnitude, which scaling by a power of ten can't do without over- or underflowing.
+impl<T> RoundToSigFigs for T
+where
+    T: Float + fmt::LowerExp + FromStr,
+    T::Err: fmt::Debug,
+{
+    fn round_to_sig_figs(self, sig_figs: u32) -> Self {
+        assert!(
+            sig_figs > 0,
+            "The number of significant figures must be greater than zero."
+        );
+        if self.is_zero() {
+            return self;
+        }
+        let precision = sig_figs as usize - 1;
+        format!("{:.*e}", precision, self)
+            .parse()
+            .expect("formatted float should parse")
+    }
+}
+
+/// Trait that provides a way to round floats to a multiple of some step size
+pub trait SnapTo: Sized {
+    /// Returns the multiple of `step` nearest to `self`. Half-way cases round away from 0.0.
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't greater than zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::SnapTo;
+    /// assert_eq!(37.0.snap_to(8.0), 40.0);
+    /// assert_eq!(35.0.snap_to(8.0), 32.0);
+    /// assert_eq!((-0.7).snap_to(0.5), -0.5);
+    /// ```
+    fn snap_to(self, step: Self) -> Self;
+
+    /// Returns the largest multiple of `step` that is less than or equal to `self`. Values within
+    /// a few units in the last place of a multiple are treated as being that multiple.
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't greater than zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::SnapTo;
+    /// assert_eq!(39.0.snap_floor_to(8.0), 32.0);
+    /// assert_eq!((-1.0).snap_floor_to(8.0), -8.0);
+    /// assert_eq!(0.75.snap_floor_to(0.25), 0.75);
+    /// ```
+    fn snap_floor_to(self, step: Self) -> Self;
+
+    /// Returns the smallest multiple of `step` that is greater than or equal to `self`. Values
+    /// within a few units in the last place of a multiple are treated as being that multiple.
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't greater than zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::SnapTo;
+    /// assert_eq!(33.0.snap_ceil_to(8.0), 40.0);
+    /// assert_eq!((-1.0).snap_ceil_to(8.0), 0.0);
+    /// assert_eq!(0.75.snap_ceil_to(0.25), 0.75);
+    /// ```
+    fn snap_ceil_to(self, step: Self) -> Self;
+}
+
+impl<T: Float> SnapTo for T {
+    fn snap_to(self, step: Self) -> Self {
+        assert!(step > T::zero(), "The step must be greater than zero.");
+        (self / step).round() * step
+    }
+
+    fn snap_floor_to(self, step: Self) -> Self {
+        assert!(step > T::zero(), "The step must be greater than zero.");
+        let steps = self / step;
+        let nearest = steps.round();
+        if is_practically(steps, nearest) {
+            nearest * step
+        } else {
+            steps.floor() * step
+        }
+    }
+
+    fn snap_ceil_to(self, step: Self) -> Self {
+        assert!(step > T::zero(), "The step must be greater than zero.");
+        let steps = self / step;
+        let nearest = steps.round();
+        if is_practically(steps, nearest) {
+            nearest * step
+        } else {
+            steps.ceil() * step
+        }
+    }
+}
+
+/// Trait that provides a way to format floats for display to humans
+pub trait FormatFixed {
+    /// Formats `self` with at most `max_decimals` decimals (rounding to the nearest), trimming any
+    /// trailing zeros, and never using exponent notation regardless of magnitude. A result that
+    /// rounds to zero is always formatted as `"0"`, never `"-0"`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::FormatFixed;
+    /// assert_eq!(1e-7.format_fixed(8), "0.0000001");
+    /// assert_eq!(1e-7.format_fixed(2), "0");
+    /// assert_eq!(2.50.format_fixed(3), "2.5");
+    /// assert_eq!((-1234.5678_f32).format_fixed(2), "-1234.57");
+    /// assert_eq!(1e21.format_fixed(2), "1000000000000000000000");
+    /// ```
+    fn format_fixed(self, max_decimals: usize) -> String;
+}
+
+impl<T: Float + fmt::Display> FormatFixed for T {
+    fn format_fixed(self, max_decimals: usize) -> String {
+        let mut formatted = format!("{:.*}", max_decimals, self);
+        if formatted.contains('.') {
+            let trimmed_len = formatted.trim_end_matches('0').trim_end_matches('.').len();
+            formatted.truncate(trimmed_len);
+        }
+        if formatted == "-0" {
+            formatted.remove(0);
+        }
+        formatted
+    }
+}
+
+/// Trait that provides bit-level float utilities, for e.g. convergence checks in numeric code
+pub trait Ulps: Sized {
+    /// Returns the number of representable values (units in the last place) between `self` and
+    /// `other`, e.g. `1` for two adjacent values. `0.0` and `-0.0` are considered the same value.
+    /// If either value is `NaN`, returns [`u64::MAX`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Ulps;
+    /// assert_eq!(1.0.ulps_between(1.0 + f64::EPSILON), 1);
+    /// assert_eq!((0.1 + 0.2).ulps_between(0.3), 1);
+    /// assert_eq!(f64::from_bits(1).ulps_between(-f64::from_bits(1)), 2);
+    /// assert_eq!(f32::MAX.ulps_between(f32::INFINITY), 1);
+    /// ```
+    fn ulps_between(self, other: Self) -> u64;
+
+    /// Returns the smallest representable value greater than `self`. `NaN` and positive infinity
+    /// are returned unchanged, and both zeros step up to the smallest positive subnormal value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Ulps;
+    /// assert_eq!(1.0_f64.ulp_up(), 1.0 + f64::EPSILON);
+    /// assert_eq!(f32::MAX.ulp_up(), f32::INFINITY);
+    /// ```
+    fn ulp_up(self) -> Self;
+
+    /// Returns the largest representable value less than `self`. `NaN` and negative infinity
+    /// are returned unchanged, and both zeros step down to the largest negative subnormal value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Ulps;
+    /// assert_eq!(1.0_f64.ulp_down(), 1.0 - f64::EPSILON / 2.0);
+    /// assert_eq!(f32::NEG_INFINITY.ulp_down(), f32::NEG_INFINITY);
+    /// ```
+    fn ulp_down(self) -> Self;
+
+    /// Returns `true` if `self` and `other` are at most `max_ulps` representable values apart
+    /// (units in the last place.) Zeros of either sign are equal, but otherwise values of opposite
+    /// sign are never approximately equal, and neither is `NaN`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Ulps;
+    /// assert!((0.1 + 0.2).approx_eq_ulps(0.3, 1));
+    /// assert!(!(0.1 + 0.2).approx_eq_ulps(0.3, 0));
+    /// assert!(0.0.approx_eq_ulps(-0.0, 0));
+    /// ```
+    fn approx_eq_ulps(self, other: Self, max_ulps: u32) -> bool;
+}
+
+impl Ulps for f64 {
+    fn ulps_between(self, other: Self) -> u64 {
+        if self.is_nan() || other.is_nan() {
+            return u64::MAX;
+        }
+        // Reinterpreting the bits as a signed integer orders positive floats correctly, while
+        // negative floats are ordered backwards; flipping those (and mapping `-0.0` onto `0.0`)
+        // yields integers that are ordered, and spaced, exactly like the floats.
+        let ordered = |value: Self| {
+            let bits = value.to_bits() as i64;
+            if bits < 0 {
+                i64::MIN - bits
+            } else {
+                bits
+            }
+        };
+        ordered(self).abs_diff(ordered(other))
+    }
+
+    fn ulp_up(self) -> Self {
+        if self.is_nan() || self == Self::INFINITY {
+            self
+        } else if self == 0.0 {
+            Self::from_bits(1)
+        } else if self > 0.0 {
+            Self::from_bits(self.to_bits() + 1)
+        } else {
+            Self::from_bits(self.to_bits() - 1)
+        }
+    }
+
+    fn ulp_down(self) -> Self {
+        -(-self).ulp_up()
+    }
+
+    #[allow(clippy::float_cmp)]
+    fn approx_eq_ulps(self, other: Self, max_ulps: u32) -> bool {
+        if self.is_nan() || other.is_nan() {
+            false
+        } else if self.is_sign_positive() != other.is_sign_positive() {
+            self == other
+        } else {
+            self.to_bits().abs_diff(other.to_bits()) <= u64::from(max_ulps)
+        }
+    }
+}
+
+impl Ulps for f32 {
+    fn ulps_between(self, other: Self) -> u64 {
+        if self.is_nan() || other.is_nan() {
+            return u64::MAX;
+        }
+        // Reinterpreting the bits as a signed integer orders positive floats correctly, while
+        // negative floats are ordered backwards; flipping those (and mapping `-0.0` onto `0.0`)
+        // yields integers that are ordered, and spaced, exactly like the floats.
+        let ordered = |value: Self| {
+            let bits = value.to_bits() as i32;
+            let ordered = if bits < 0 { i32::MIN - bits } else { bits };
+            i64::from(ordered)
+        };
+        ordered(self).abs_diff(ordered(other))
+    }
+
+    fn ulp_up(self) -> Self {
+        if self.is_nan() || self == Self::INFINITY {
+            self
+        } else if self == 0.0 {
+            Self::from_bits(1)
+        } else if self > 0.0 {
+            Self::from_bits(self.to_bits() + 1)
+        } else {
+            Self::from_bits(self.to_bits() - 1)
+        }
+    }
+
+    fn ulp_down(self) -> Self {
+        -(-self).ulp_up()
+    }
+
+    #[allow(clippy::float_cmp)]
+    fn approx_eq_ulps(self, other: Self, max_ulps: u32) -> bool {
+        if self.is_nan() || other.is_nan() {
+            false
+        } else if self.is_sign_positive() != other.is_sign_positive() {
+            self == other
+        } else {
+            self.to_bits().abs_diff(other.to_bits()) <= max_ulps
+        }
+    }
+}
+
+/// Trait that provides ways to compare floats for approximate equality
+pub trait ApproxEq: Sized {
+    /// Returns `true` if `self` and `other` are within `abs_tol` of each other, or within
+    /// `rel_tol` times the larger of their magnitudes. The absolute tolerance handles values close
+    /// to zero, where a relative tolerance is of little use. Equal values (including equal
+    /// infinities) are always approximately equal, while `NaN` is never approximately equal to
+    /// anything.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::ApproxEq;
+    /// assert!((0.1 + 0.2).approx_eq(0.3, 0.0, 1e-9));
+    /// assert!(1e-12.approx_eq(0.0, 1e-9, 0.0));
+    /// assert!(!1e-12.approx_eq(0.0, 0.0, 1e-9));
+    /// assert!(1_000_000.0.approx_eq(1_000_001.0, 0.0, 1e-6));
+    /// ```
+    fn approx_eq(self, other: Self, abs_tol: Self, rel_tol: Self) -> bool;
+}
+
+impl<T: Float> ApproxEq for T {
+    fn approx_eq(self, other: Self, abs_tol: Self, rel_tol: Self) -> bool {
+        if self == other {
+            return true;
+        }
+        if !self.is_finite() || !other.is_finite() {
+            return false;
+        }
+        let difference = (self - other).abs();
+        difference <= abs_tol || difference <= rel_tol * self.abs().max(other.abs())
+    }
+}
+
+/// Trait that provides linear interpolation between floats
+pub trait Lerp: Sized {
+    /// Linearly interpolates between `self` (at `t == 0.0`) and `to` (at `t == 1.0`). Values of `t`
+    /// outside `0.0..=1.0` extrapolate beyond the end points; see [`Lerp::lerp_clamped`] if that's
+    /// not desired. Both end points are returned exactly.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Lerp;
+    /// assert_eq!(10.0.lerp(20.0, 0.25), 12.5);
+    /// assert_eq!(10.0.lerp(20.0, 1.5), 25.0);
+    /// ```
+    fn lerp(self, to: Self, t: Self) -> Self;
+
+    /// Like [`Lerp::lerp`], but with `t` clamped to `0.0..=1.0`, so that the result always lies
+    /// between `self` and `to`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Lerp;
+    /// assert_eq!(10.0.lerp_clamped(20.0, 0.25), 12.5);
+    /// assert_eq!(10.0.lerp_clamped(20.0, 1.5), 20.0);
+    /// ```
+    fn lerp_clamped(self, to: Self, t: Self) -> Self;
+
+    /// The inverse of [`Lerp::lerp`]: returns the `t` at which `self` lies when interpolating from
+    /// `from` to `to`. Values outside the range produce a `t` outside `0.0..=1.0`. If `from` and
+    /// `to` are equal, the result is infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Lerp;
+    /// assert_eq!(12.5.inverse_lerp(10.0, 20.0), 0.25);
+    /// assert_eq!(5.0.inverse_lerp(10.0, 20.0), -0.5);
+    /// ```
+    fn inverse_lerp(self, from: Self, to: Self) -> Self;
+
+    /// Like [`Lerp::inverse_lerp`], but with the result clamped to `0.0..=1.0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Lerp;
+    /// assert_eq!(12.5.inverse_lerp_clamped(10.0, 20.0), 0.25);
+    /// assert_eq!(5.0.inverse_lerp_clamped(10.0, 20.0), 0.0);
+    /// ```
+    fn inverse_lerp_clamped(self, from: Self, to: Self) -> Self;
+
+    /// Maps `self` proportionally from the range `from` to the range `to`, such that `from.start`
+    /// maps to `to.start` and `from.end` maps to `to.end`. Both ends of the ranges are treated as
+    /// points to interpolate between, so either range may be descending. If `clamp` is `true`, the
+    /// result is kept within `to`; otherwise values outside `from` are extrapolated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::Lerp;
+    /// assert_eq!(512.0.remap(0.0..1024.0, -1.0..1.0, false), 0.0);
+    /// assert_eq!(2048.0.remap(0.0..1024.0, -1.0..1.0, false), 3.0);
+    /// assert_eq!(2048.0.remap(0.0..1024.0, -1.0..1.0, true), 1.0);
+    /// assert_eq!(0.25.remap(0.0..1.0, 100.0..0.0, false), 75.0);
+    /// ```
+    fn remap(self, from: Range<Self>, to: Range<Self>, clamp: bool) -> Self;
+}
+
+impl<T: Float> Lerp for T {
+    fn lerp(self, to: Self, t: Self) -> Self {
+        self * (T::one() - t) + to * t
+    }
+
+    fn lerp_clamped(self, to: Self, t: Self) -> Self {
+        self.lerp(to, clamp(t, T::zero(), T::one()))
+    }
+
+    fn inverse_lerp(self, from: Self, to: Self) -> Self {
+        (self - from) / (to - from)
+    }
+
+    fn inverse_lerp_clamped(self, from: Self, to: Self) -> Self {
+        clamp(self.inverse_lerp(from, to), T::zero(), T::one())
+    }
+
+    fn remap(self, from: Range<Self>, to: Range<Self>, clamp: bool) -> Self {
+        let t = if clamp {
+            self.inverse_lerp_clamped(from.start, from.end)
+        } else {
+            self.inverse_lerp(from.start, from.end)
+        };
+        to.start.lerp(to.end, t)
+    }
+}
+
+/// Trait that provides helpers for floats representing fractions, like opacities and progress
+pub trait UnitInterval: Sized {
+    /// Returns `self` clamped to `0.0..=1.0`. `NaN` is returned unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::UnitInterval;
+    /// assert_eq!(1.25.clamp01(), 1.0);
+    /// assert_eq!((-0.5).clamp01(), 0.0);
+    /// assert_eq!(0.5.clamp01(), 0.5);
+    /// ```
+    fn clamp01(self) -> Self;
+
+    /// Converts a fraction to a percentage, i.e. `0.25` becomes `25.0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::UnitInterval;
+    /// assert_eq!(0.25.to_percent(), 25.0);
+    /// assert_eq!(1.5.to_percent(), 150.0);
+    /// ```
+    fn to_percent(self) -> Self;
+
+    /// Converts a percentage to a fraction, i.e. `25.0` becomes `0.25`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::float_extensions::UnitInterval;
+    /// assert_eq!(25.0.from_percent(), 0.25);
+    /// assert_eq!((-50.0_f32).from_percent(), -0.5);
+    /// ```
+    // Named to mirror `to_percent`, even though it converts `self` rather than constructing a value
+    #[allow(clippy::wrong_self_convention)]
+    fn from_percent(self) -> Self;
+}
+
+impl<T: Float> UnitInterval for T {
+    fn clamp01(self) -> Self {
+        clamp(self, T::zero(), T::one())
+    }
+
+    fn to_percent(self) -> Self {
+        self * cast(100.0)
+    }
+
+    fn from_percent(self) -> Self {
+        self / cast(100.0)
     }
 }
 
@@ -39,4 +611,133 @@ mod tests {
         assert_float_eq!(value.round_to(7), 1.234_567_9, abs <= 0.000_000_1);
         assert_float_eq!(value.round_to(8), 1.234_567_89, abs <= 0.000_000_01);
     }
+
+    #[test]
+    fn approx_eq_handles_special_values() {
+        assert!(f64::INFINITY.approx_eq(f64::INFINITY, 0.0, 0.0));
+        assert!(!f64::INFINITY.approx_eq(f64::NEG_INFINITY, 1.0, 1.0));
+        assert!(!f64::NAN.approx_eq(f64::NAN, 1.0, 1.0));
+        assert!(!1.0_f32.approx_eq(f32::INFINITY, 1.0, 1.0));
+
+        assert!(!f32::NAN.approx_eq_ulps(f32::NAN, u32::MAX));
+        assert!(f32::MAX.approx_eq_ulps(f32::INFINITY, 1));
+        assert!(!f64::MIN_POSITIVE.approx_eq_ulps(-f64::MIN_POSITIVE, u32::MAX));
+    }
+
+    #[test]
+    fn lerp_round_trips_through_inverse_lerp() {
+        for &t in &[0.0, 0.1, 0.5, 0.9, 1.0] {
+            let value = 0.3_f64.lerp(-7.9, t);
+            assert_float_eq!(value.inverse_lerp(0.3, -7.9), t, abs <= 1e-12);
+        }
+        assert_eq!(0.3_f32.lerp(-7.9, 1.0), -7.9);
+        assert_eq!(2.0_f32.lerp_clamped(4.0, -1.0), 2.0);
+    }
+
+    #[test]
+    fn remap_clamps_to_descending_ranges() {
+        assert_eq!(1.5_f32.remap(1.0..2.0, 10.0..0.0, false), 5.0);
+        assert_eq!((-3.0_f32).remap(1.0..2.0, 10.0..0.0, true), 10.0);
+        assert_eq!(7.0_f32.remap(2.0..1.0, 10.0..0.0, true), 10.0);
+    }
+
+    #[test]
+    fn round_to_sig_figs_spans_magnitudes() {
+        assert_eq!(f64::MAX.round_to_sig_figs(1), f64::INFINITY);
+        assert_eq!(f64::MIN_POSITIVE.round_to_sig_figs(3), 2.23e-308);
+        assert_eq!(123.456_f32.round_to_sig_figs(4), 123.5);
+        assert_eq!(1e30_f32.round_to_sig_figs(20), 1e30);
+        assert!(f32::NAN.round_to_sig_figs(2).is_nan());
+        assert_eq!(f64::NEG_INFINITY.round_to_sig_figs(2), f64::NEG_INFINITY);
+        assert!((-0.0_f64).round_to_sig_figs(2).is_sign_negative());
+    }
+
+    #[test]
+    fn floor_to_and_ceil_to_tolerate_representation_error() {
+        for &price in &[0.07_f64, 4.35, 1.15, 8.2, 19.99] {
+            assert_eq!(price.floor_to(2), price);
+            assert_eq!(price.ceil_to(2), price);
+        }
+        assert_eq!(2.675_f32.floor_to(2), 2.67);
+        assert_eq!(2.675_f32.ceil_to(2), 2.68);
+        assert_eq!(2.0_f32.ceil_to(0), 2.0);
+    }
+
+    #[test]
+    fn round_to_even_only_differs_on_ties() {
+        assert_eq!(0.125_f32.round_to_even(2), 0.12);
+        assert_eq!(0.125_f32.round_to(2), 0.13);
+        assert_eq!(0.375_f32.round_to_even(2), 0.38);
+        assert_eq!(1.5_f64.round_to_even(0), 2.0);
+        assert_eq!(2.5_f64.round_to_even(0), 2.0);
+        assert_eq!(2.51_f64.round_to_even(0), 3.0);
+    }
+
+    #[test]
+    fn snap_floor_to_and_snap_ceil_to_tolerate_representation_error() {
+        assert_float_eq!(0.3_f64.snap_floor_to(0.1), 0.3, ulps <= 1);
+        assert_float_eq!(0.3_f64.snap_ceil_to(0.1), 0.3, ulps <= 1);
+        assert_float_eq!(0.7_f32.snap_floor_to(0.1), 0.7, ulps <= 1);
+        assert_float_eq!(0.71_f32.snap_ceil_to(0.1), 0.8, ulps <= 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "The step must be greater than zero.")]
+    fn snap_to_rejects_zero_steps() {
+        let _ = 1.0_f64.snap_to(0.0);
+    }
+
+    #[test]
+    fn format_fixed_handles_special_values() {
+        assert_eq!((-0.0_f64).format_fixed(3), "0");
+        assert_eq!((-0.0001_f64).format_fixed(3), "0");
+        assert_eq!(100.0_f64.format_fixed(0), "100");
+        assert_eq!(f32::NAN.format_fixed(2), "NaN");
+        assert_eq!(f64::NEG_INFINITY.format_fixed(2), "-inf");
+    }
+
+    #[test]
+    fn ulp_up_and_ulp_down_step_one_ulp() {
+        let values = [
+            -1.5_f64,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            1e-310,
+            3.0,
+            f64::MAX,
+        ];
+        for &value in &values {
+            assert_eq!(value.ulps_between(value.ulp_up()), 1);
+            assert_eq!(value.ulps_between(value.ulp_down()), 1);
+        }
+        assert_eq!((-0.0_f32).ulp_up(), f32::from_bits(1));
+        assert_eq!(0.0_f32.ulp_down(), -f32::from_bits(1));
+        assert!(f32::NAN.ulp_up().is_nan());
+        assert_eq!(f32::INFINITY.ulp_up(), f32::INFINITY);
+        assert_eq!(0.0_f32.ulps_between(-0.0), 0);
+        assert_eq!(f64::NAN.ulps_between(1.0), u64::MAX);
+        assert_eq!(
+            f32::MIN.ulps_between(f32::MAX),
+            2 * u64::from(0x7f7f_ffff_u32)
+        );
+    }
+
+    #[test]
+    fn extensions_are_available_for_any_float() {
+        fn midpoint_snapped<T: Float>(from: T, to: T, step: T) -> T {
+            from.lerp(to, T::from(0.5).unwrap()).snap_to(step)
+        }
+
+        assert_eq!(midpoint_snapped(0.0_f32, 10.0, 4.0), 4.0);
+        assert_eq!(midpoint_snapped(0.0_f64, 14.0, 4.0), 8.0);
+    }
+
+    #[test]
+    fn percentages_round_trip() {
+        for &fraction in &[0.0_f64, 0.07, 0.333, 1.0, 2.5] {
+            assert_float_eq!(fraction.to_percent().from_percent(), fraction, ulps <= 1);
+        }
+        assert!(f32::NAN.clamp01().is_nan());
+    }
 }
@@ -1,5 +1,49 @@
 //! Various [`Iterator`] extensions
 
+mod array_chunks;
+mod chunks;
+mod dedup;
+mod duplicates;
+mod flatten_ok;
+mod inspect_indexed;
+mod interleave;
+mod intersperse;
+mod partition;
+mod product;
+mod running;
+mod select;
+mod split_when;
+mod take_while_inclusive;
+mod unique;
+mod windowed;
+mod with_position;
+mod zip_longest;
+
+pub use array_chunks::ArrayChunks;
+pub use chunks::Chunks;
+pub use dedup::DedupByKey;
+pub use duplicates::Duplicates;
+pub use flatten_ok::FlattenOk;
+pub use inspect_indexed::InspectIndexed;
+pub use interleave::Interleave;
+pub use intersperse::{Intersperse, IntersperseWith};
+pub use partition::{PartitionLeft, PartitionRight};
+pub use product::CartesianProduct;
+pub use running::{CumulativeSum, RunningFold};
+pub use split_when::SplitWhen;
+pub use take_while_inclusive::TakeWhileInclusive;
+pub use unique::{Unique, UniqueBy};
+pub use windowed::Windowed;
+pub use with_position::{Position, WithPosition};
+pub use zip_longest::{EitherOrBoth, ZipLongest};
+
+use select::Keyed;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+use std::vec;
+
 /// An [`Iterator`] extension trait that provides extra iterator methods.
 pub trait IteratorExtensions: Iterator {
     /// Consumes an iterator, creating two collections from it.
@@ -61,6 +105,1323 @@ pub trait IteratorExtensions: Iterator {
 
         (left, right)
     }
-}
 
-impl<T: ?Sized + Iterator> IteratorExtensions for T {}
+    /// Consumes an iterator of [`Result`]s, collecting the [`Ok`] values into one collection and
+    /// the [`Err`] values into another.
+    ///
+    /// Unlike collecting into a `Result<Vec<T>, E>`, this doesn't stop at the first error, so
+    /// every error can be reported at the end.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let (numbers, errors): (Vec<i32>, Vec<_>) = ["1", "x", "3", "y"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<i32>())
+    ///     .partition_result();
+    ///
+    /// assert_eq!(numbers, [1, 3]);
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    fn partition_result<T, E, A, B>(self) -> (A, B)
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        A: Default + Extend<T>,
+        B: Default + Extend<E>,
+    {
+        let mut oks: A = Default::default();
+        let mut errs: B = Default::default();
+
+        self.for_each(|x| match x {
+            Ok(ok) => oks.extend(Some(ok)),
+            Err(err) => errs.extend(Some(err)),
+        });
+
+        (oks, errs)
+    }
+
+    /// Splits an iterator into two lazy iterators, like a lazy version of
+    /// [`partition_map()`](Self::partition_map).
+    ///
+    /// Elements for which `predicate` returns `true` are passed through `left_map` and yielded by
+    /// the first iterator; the rest are passed through `right_map` and yielded by the second.
+    /// Pulling from one side buffers the mapped elements meant for the other side until they are
+    /// asked for, so consuming only one side never materializes the other side beyond what had to
+    /// be skipped over.
+    ///
+    /// The returned iterators share the original iterator through an [`Rc`](std::rc::Rc), so they
+    /// can't be sent to other threads.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let (evens, odds) = (1..=10).partition_map_lazy(|n| n % 2 == 0, |n| n, |n| n * 100);
+    ///
+    /// assert_eq!(evens.take(2).collect::<Vec<_>>(), [2, 4]);
+    /// assert_eq!(odds.collect::<Vec<_>>(), [100, 300, 500, 700, 900]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn partition_map_lazy<P, L, LT, R, RT>(
+        self,
+        predicate: P,
+        left_map: L,
+        right_map: R,
+    ) -> (
+        PartitionLeft<Self, P, L, R, LT, RT>,
+        PartitionRight<Self, P, L, R, LT, RT>,
+    )
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+        L: FnMut(Self::Item) -> LT,
+        R: FnMut(Self::Item) -> RT,
+    {
+        partition::partition_map_lazy(self, predicate, left_map, right_map)
+    }
+
+    /// Consumes an iterator, creating `n` collections from it.
+    ///
+    /// The closure passed to `partition_n()` returns the index of the collection each element
+    /// belongs in, which must be less than `n`. This generalizes [`Iterator::partition()`] to more
+    /// than two outputs.
+    ///
+    /// # Panics
+    ///
+    /// If `classify` returns an index that is `n` or greater.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let a = [1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let by_remainder: Vec<Vec<&i32>> = a.iter().partition_n(3, |&&n| n as usize % 3);
+    ///
+    /// assert_eq!(by_remainder, [vec![&3, &6], vec![&1, &4, &7], vec![&2, &5]]);
+    /// ```
+    fn partition_n<F, A>(self, n: usize, mut classify: F) -> Vec<A>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> usize,
+        A: Default + Extend<Self::Item>,
+    {
+        let mut buckets: Vec<A> = std::iter::repeat_with(Default::default).take(n).collect();
+
+        self.for_each(|x| {
+            let index = classify(&x);
+            assert!(
+                index < n,
+                "partition index {} out of range for {} buckets",
+                index,
+                n
+            );
+            buckets[index].extend(Some(x));
+        });
+
+        buckets
+    }
+
+    /// Consumes an iterator, creating a collection for each distinct key returned by `key`.
+    ///
+    /// Each element is added to the collection belonging to its key, in iteration order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+    ///
+    /// let by_letter: HashMap<char, Vec<&&str>> =
+    ///     words.iter().partition_by_key(|w| w.chars().next().unwrap());
+    ///
+    /// assert_eq!(by_letter.len(), 3);
+    /// assert_eq!(by_letter[&'a'], [&"apple", &"avocado"]);
+    /// assert_eq!(by_letter[&'c'], [&"cherry"]);
+    /// ```
+    fn partition_by_key<F, K, A>(self, mut key: F) -> HashMap<K, A>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash,
+        A: Default + Extend<Self::Item>,
+    {
+        let mut buckets: HashMap<K, A> = HashMap::new();
+
+        self.for_each(|x| {
+            buckets.entry(key(&x)).or_default().extend(Some(x));
+        });
+
+        buckets
+    }
+
+    /// Consumes an iterator, grouping its elements into a [`Vec`] for each distinct key returned
+    /// by `key`.
+    ///
+    /// This is [`partition_by_key()`](Self::partition_by_key) with the collections fixed to
+    /// [`Vec`]. Use [`group_by_key_ordered()`](Self::group_by_key_ordered) for a map that keeps
+    /// its keys sorted.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let lengths = ["a", "bb", "cc", "d", "eee"].iter().group_by_key(|s| s.len());
+    ///
+    /// assert_eq!(lengths[&1], [&"a", &"d"]);
+    /// assert_eq!(lengths[&2], [&"bb", &"cc"]);
+    /// assert_eq!(lengths[&3], [&"eee"]);
+    /// ```
+    fn group_by_key<F, K>(self, key: F) -> HashMap<K, Vec<Self::Item>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash,
+    {
+        self.partition_by_key(key)
+    }
+
+    /// Consumes an iterator, grouping its elements into a [`Vec`] for each distinct key returned
+    /// by `key`, in a [`BTreeMap`] so that the groups can be visited in key order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let by_tens = [31, 12, 35, 17, 20].iter().group_by_key_ordered(|&&n| n / 10);
+    ///
+    /// let groups: Vec<_> = by_tens.into_iter().collect();
+    /// assert_eq!(groups, [(1, vec![&12, &17]), (2, vec![&20]), (3, vec![&31, &35])]);
+    /// ```
+    fn group_by_key_ordered<F, K>(self, mut key: F) -> BTreeMap<K, Vec<Self::Item>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        let mut groups: BTreeMap<K, Vec<Self::Item>> = BTreeMap::new();
+
+        self.for_each(|x| {
+            groups.entry(key(&x)).or_default().push(x);
+        });
+
+        groups
+    }
+
+    /// Consumes an iterator, counting how many times each distinct element occurs.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let counts = "hello".chars().counts();
+    ///
+    /// assert_eq!(counts.len(), 4);
+    /// assert_eq!(counts[&'l'], 2);
+    /// assert_eq!(counts[&'o'], 1);
+    /// ```
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+
+        self.for_each(|x| *counts.entry(x).or_default() += 1);
+
+        counts
+    }
+
+    /// Consumes an iterator, counting how many elements there are for each distinct key returned
+    /// by `key`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let parity = [1, 2, 3, 4, 5].iter().counts_by(|&&n| n % 2 == 0);
+    ///
+    /// assert_eq!(parity[&true], 2);
+    /// assert_eq!(parity[&false], 3);
+    /// ```
+    fn counts_by<F, K>(self, mut key: F) -> HashMap<K, usize>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+
+        self.for_each(|x| *counts.entry(key(&x)).or_default() += 1);
+
+        counts
+    }
+
+    /// Returns both the minimum and the maximum element of an iterator in a single pass, or
+    /// [`None`] if the iterator is empty.
+    ///
+    /// Like [`Iterator::min()`] and [`Iterator::max()`], the first of several equally minimum
+    /// elements and the last of several equally maximum elements are returned.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// assert_eq!([3, 1, 4, 1, 5].iter().min_max(), Some((&1, &5)));
+    /// assert_eq!([7].iter().min_max(), Some((&7, &7)));
+    /// assert_eq!(std::iter::empty::<i32>().min_max(), None);
+    /// ```
+    fn min_max(self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.fold(None, |acc, x| match acc {
+            None => Some((x.clone(), x)),
+            Some((min, max)) => Some(if x < min {
+                (x, max)
+            } else if x >= max {
+                (min, x)
+            } else {
+                (min, max)
+            }),
+        })
+    }
+
+    /// Returns the elements that give the minimum and the maximum value from the function `key`
+    /// in a single pass, or [`None`] if the iterator is empty.
+    ///
+    /// Like [`Iterator::min_by_key()`] and [`Iterator::max_by_key()`], the first of several equally
+    /// minimum elements and the last of several equally maximum elements are returned.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let words = ["pear", "fig", "banana", "kiwi"];
+    ///
+    /// assert_eq!(
+    ///     words.iter().min_max_by_key(|w| w.len()),
+    ///     Some((&"fig", &"banana"))
+    /// );
+    /// ```
+    fn min_max_by_key<F, K>(self, mut key: F) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord + Clone,
+    {
+        self.fold(None, |acc, x| {
+            let k = key(&x);
+            match acc {
+                None => Some(((k.clone(), x.clone()), (k, x))),
+                Some(((min_k, min), (max_k, max))) => Some(if k < min_k {
+                    ((k, x), (max_k, max))
+                } else if k >= max_k {
+                    ((min_k, min), (k, x))
+                } else {
+                    ((min_k, min), (max_k, max))
+                }),
+            }
+        })
+        .map(|((_, min), (_, max))| (min, max))
+    }
+
+    /// Consumes an iterator, returning whether all of its elements are distinct. Stops at the
+    /// first element that has been seen before.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// assert!([1, 2, 3].iter().all_unique());
+    /// assert!(![1, 2, 1].iter().all_unique());
+    /// ```
+    fn all_unique(mut self) -> bool
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        let mut seen = HashSet::new();
+        self.all(|x| seen.insert(x))
+    }
+
+    /// Consumes an iterator, returning the arithmetic mean of its elements, or [`None`] if it is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// assert_eq!([1, 2, 3, 4].iter().copied().mean(), Some(2.5));
+    /// assert_eq!(std::iter::empty::<f64>().mean(), None);
+    /// ```
+    fn mean(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        let (count, sum) = self.fold((0_u64, 0.), |(count, sum), x| (count + 1, sum + x.into()));
+
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Consumes an iterator, returning the sample variance of its elements, or [`None`] if it has
+    /// fewer than two elements.
+    ///
+    /// The variance is computed in a single, numerically stable pass using Welford's algorithm.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let data = [2, 4, 4, 4, 5, 5, 7, 9];
+    ///
+    /// assert_eq!(data.iter().copied().variance(), Some(32. / 7.));
+    /// assert_eq!([1].iter().copied().variance(), None);
+    /// ```
+    fn variance(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        let (count, _, sum_of_squares) =
+            self.fold((0_u64, 0., 0.), |(count, mean, sum_of_squares), x| {
+                let x = x.into();
+                let count = count + 1;
+                let delta = x - mean;
+                let mean = mean + delta / count as f64;
+                (count, mean, sum_of_squares + delta * (x - mean))
+            });
+
+        (count > 1).then(|| sum_of_squares / (count - 1) as f64)
+    }
+
+    /// Consumes an iterator, returning the sample standard deviation of its elements, or [`None`]
+    /// if it has fewer than two elements. See [`variance()`](Self::variance).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// assert_eq!([1, 3].iter().copied().std_dev(), Some(2_f64.sqrt()));
+    /// ```
+    fn std_dev(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Consumes an iterator, returning the `p`th percentile of its elements, or [`None`] if it is
+    /// empty.
+    ///
+    /// The result is exact: the elements are collected and the neighboring ranks around the
+    /// percentile are selected, linearly interpolating between them when the percentile falls
+    /// between two elements. NaN elements sort above positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not in the range `0.0..=100.0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let data = [15, 20, 35, 40, 50];
+    ///
+    /// assert_eq!(data.iter().copied().percentile(50.), Some(35.));
+    /// assert_eq!(data.iter().copied().percentile(25.), Some(20.));
+    /// assert_eq!(data.iter().copied().percentile(90.), Some(46.));
+    /// assert_eq!(data.iter().copied().percentile(100.), Some(50.));
+    /// ```
+    fn percentile(self, p: f64) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "percentile must be between 0 and 100, was {}",
+            p
+        );
+
+        let mut values: Vec<f64> = self.map(Into::into).collect();
+        if values.is_empty() {
+            return None;
+        }
+
+        let rank = p / 100. * (values.len() - 1) as f64;
+        // rank is within 0..values.len() since p is within 0..=100.
+        let lower_index = rank.floor() as usize;
+        let (_, &mut lower, above) = values.select_nth_unstable_by(lower_index, f64::total_cmp);
+
+        let fraction = rank - rank.floor();
+        match above.iter().copied().min_by(f64::total_cmp) {
+            Some(upper) if fraction > 0. => Some(lower + (upper - lower) * fraction),
+            _ => Some(lower),
+        }
+    }
+
+    /// Consumes an iterator, returning its `k` largest elements in descending order.
+    ///
+    /// Only `k` elements are kept in memory at a time, so this is much cheaper than sorting when
+    /// `k` is small compared to the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// assert_eq!((0..1_000_000).top_k(3), [999_999, 999_998, 999_997]);
+    /// assert_eq!([2, 1].iter().top_k(5), [&2, &1]);
+    /// ```
+    fn top_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        select::largest(self, k)
+    }
+
+    /// Consumes an iterator, returning the `k` elements with the largest keys as returned by
+    /// `key`, in descending order of key. See [`top_k()`](Self::top_k).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let words = ["a", "abcd", "ab", "abc"];
+    ///
+    /// assert_eq!(words.iter().top_k_by_key(2, |w| w.len()), [&"abcd", &"abc"]);
+    /// ```
+    fn top_k_by_key<F, K>(self, k: usize, mut key: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        let keyed = self.map(|item| Keyed {
+            key: key(&item),
+            item,
+        });
+        select::largest(keyed, k)
+            .into_iter()
+            .map(|x| x.item)
+            .collect()
+    }
+
+    /// Consumes an iterator, returning its `k` smallest elements in ascending order. See
+    /// [`top_k()`](Self::top_k).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// assert_eq!([5, 1, 4, 2, 3].iter().bottom_k(2), [&1, &2]);
+    /// ```
+    fn bottom_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        select::largest(self.map(Reverse), k)
+            .into_iter()
+            .map(|x| x.0)
+            .collect()
+    }
+
+    /// Consumes an iterator, returning the `k` elements with the smallest keys as returned by
+    /// `key`, in ascending order of key. See [`top_k()`](Self::top_k).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let words = ["abc", "a", "abcd", "ab"];
+    ///
+    /// assert_eq!(words.iter().bottom_k_by_key(2, |w| w.len()), [&"a", &"ab"]);
+    /// ```
+    fn bottom_k_by_key<F, K>(self, k: usize, mut key: F) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        let keyed = self.map(|item| Keyed {
+            key: Reverse(key(&item)),
+            item,
+        });
+        select::largest(keyed, k)
+            .into_iter()
+            .map(|x| x.item)
+            .collect()
+    }
+
+    /// Collects all the elements into a [`Vec`], sorts it and returns an iterator over the sorted
+    /// elements.
+    ///
+    /// The sort is stable. See [`slice::sort`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let sorted: Vec<_> = [3, 1, 2].iter().sorted().map(|n| n * 10).collect();
+    ///
+    /// assert_eq!(sorted, [10, 20, 30]);
+    /// ```
+    fn sorted(self) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut v: Vec<_> = self.collect();
+        v.sort();
+        v.into_iter()
+    }
+
+    /// Collects all the elements into a [`Vec`], sorts it with the comparator function `compare`
+    /// and returns an iterator over the sorted elements.
+    ///
+    /// The sort is stable. See [`slice::sort_by`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let descending: Vec<_> = [3, 1, 2].iter().sorted_by(|a, b| b.cmp(a)).collect();
+    ///
+    /// assert_eq!(descending, [&3, &2, &1]);
+    /// ```
+    fn sorted_by<F>(self, compare: F) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut v: Vec<_> = self.collect();
+        v.sort_by(compare);
+        v.into_iter()
+    }
+
+    /// Collects all the elements into a [`Vec`], sorts it by the key returned by `key` and returns
+    /// an iterator over the sorted elements.
+    ///
+    /// The sort is stable. See [`slice::sort_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let by_length: Vec<_> = ["ccc", "a", "bb"].iter().sorted_by_key(|s| s.len()).collect();
+    ///
+    /// assert_eq!(by_length, [&"a", &"bb", &"ccc"]);
+    /// ```
+    fn sorted_by_key<F, K>(self, key: F) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        let mut v: Vec<_> = self.collect();
+        v.sort_by_key(key);
+        v.into_iter()
+    }
+
+    /// Creates an iterator that yields the elements of this iterator in [`Vec`]s of `size`
+    /// elements each. The last chunk has fewer than `size` elements if the number of elements
+    /// isn't a multiple of `size`.
+    ///
+    /// This is like [`slice::chunks`], but works for any iterator.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let chunks: Vec<_> = "abcdefg".chars().chunks(3).collect();
+    ///
+    /// assert_eq!(chunks, [vec!['a', 'b', 'c'], vec!['d', 'e', 'f'], vec!['g']]);
+    /// ```
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Creates an iterator that yields the elements of this iterator in arrays of `N` elements
+    /// each, without allocating. If the number of elements isn't a multiple of `N`, the elements
+    /// left over at the end can be retrieved with [`ArrayChunks::into_remainder`].
+    ///
+    /// The standard library has an unstable method with the same name, so calling this one with
+    /// method call syntax triggers the `unstable_name_collisions` lint; call it as
+    /// `IteratorExtensions::array_chunks::<N>(iter)` to avoid that.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let points: Vec<_> = IteratorExtensions::array_chunks(vec![1, 2, 3, 4].into_iter())
+    ///     .map(|[x, y]| (x, y))
+    ///     .collect();
+    ///
+    /// assert_eq!(points, [(1, 2), (3, 4)]);
+    /// ```
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        ArrayChunks::new(self)
+    }
+
+    /// Creates an iterator that yields every run of `size` consecutive elements of this iterator
+    /// as a [`Vec`], with each window overlapping the previous one in all but one element. If
+    /// this iterator has fewer than `size` elements, no windows are yielded.
+    ///
+    /// This is like [`slice::windows`], but works for any iterator of [`Clone`] elements.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let moving_averages: Vec<_> = [2, 4, 6, 8, 10]
+    ///     .iter()
+    ///     .windowed(3)
+    ///     .map(|w| w.into_iter().sum::<i32>() / 3)
+    ///     .collect();
+    ///
+    /// assert_eq!(moving_averages, [4, 6, 8]);
+    /// ```
+    fn windowed(self, size: usize) -> Windowed<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Windowed::new(self, size)
+    }
+
+    /// Creates an iterator that skips every element whose key, as returned by `key`, is equal to
+    /// the key of the element that came before it. Only consecutive duplicates are removed, so for
+    /// an unsorted iterator, equal keys may still be yielded more than once.
+    ///
+    /// This is like [`Vec::dedup_by_key`], but works lazily on any iterator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let deduped: Vec<_> = [10, 11, 20, 21, 22, 12]
+    ///     .iter()
+    ///     .dedup_by_key(|&&n| n / 10)
+    ///     .collect();
+    ///
+    /// assert_eq!(deduped, [&10, &20, &12]);
+    /// ```
+    fn dedup_by_key<F, K>(self, key: F) -> DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        DedupByKey::new(self, key)
+    }
+
+    /// Creates an iterator that alternates between the elements of this iterator and `other`,
+    /// starting with this one. Once either runs out, the rest of the other one's elements follow.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let merged: Vec<_> = [1, 3, 5, 7, 9].iter().interleave(&[2, 4]).collect();
+    ///
+    /// assert_eq!(merged, [&1, &2, &3, &4, &5, &7, &9]);
+    /// ```
+    fn interleave<J>(self, other: J) -> Interleave<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+    {
+        Interleave::new(self, other.into_iter())
+    }
+
+    /// Creates an iterator that places a clone of `separator` between every two adjacent
+    /// elements of this iterator.
+    ///
+    /// The standard library has an unstable method with the same name, so calling this one with
+    /// method call syntax triggers the `unstable_name_collisions` lint; call it as
+    /// `IteratorExtensions::intersperse(iter, separator)` to avoid that.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let words = ["one", "two", "three"];
+    /// let joined: String = IteratorExtensions::intersperse(words.iter().copied(), ", ").collect();
+    ///
+    /// assert_eq!(joined, "one, two, three");
+    /// ```
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Intersperse::new(self, separator)
+    }
+
+    /// Creates an iterator that places a separator produced by calling `separator` between every
+    /// two adjacent elements of this iterator.
+    ///
+    /// The standard library has an unstable method with the same name, so calling this one with
+    /// method call syntax triggers the `unstable_name_collisions` lint; call it as
+    /// `IteratorExtensions::intersperse_with(iter, separator)` to avoid that.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let letters = ["a", "b", "c"].iter().copied();
+    /// let mut separators = 0;
+    /// let interspersed: Vec<_> = IteratorExtensions::intersperse_with(letters, || {
+    ///     separators += 1;
+    ///     "|"
+    /// })
+    /// .collect();
+    ///
+    /// assert_eq!(interspersed, ["a", "|", "b", "|", "c"]);
+    /// assert_eq!(separators, 2);
+    /// ```
+    fn intersperse_with<F>(self, separator: F) -> IntersperseWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> Self::Item,
+    {
+        IntersperseWith::new(self, separator)
+    }
+
+    /// Creates an iterator over every pair of an element of this iterator and an element of
+    /// `other`, in the same order as a pair of nested `for` loops with this iterator on the
+    /// outside. `other` is cloned each time it needs to start over.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let pairs: Vec<_> = (0..2).cartesian_product("ab".chars()).collect();
+    ///
+    /// assert_eq!(pairs, [(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b')]);
+    /// ```
+    fn cartesian_product<J>(self, other: J) -> CartesianProduct<Self, J::IntoIter>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        CartesianProduct::new(self, other.into_iter())
+    }
+
+    /// Creates an iterator that yields elements while `predicate` returns `true`, and then also
+    /// yields the first element for which it returns `false`.
+    ///
+    /// Unlike [`Iterator::take_while()`], the element that ends the run isn't lost, which is
+    /// usually what's wanted when scanning up to and including a terminator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let mut bytes = b"key=value;rest".iter();
+    /// let field: Vec<_> = bytes.by_ref().take_while_inclusive(|&&b| b != b';').collect();
+    ///
+    /// assert_eq!(field, b"key=value;".iter().collect::<Vec<_>>());
+    /// assert_eq!(bytes.as_slice(), b"rest");
+    /// ```
+    fn take_while_inclusive<P>(self, predicate: P) -> TakeWhileInclusive<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhileInclusive::new(self, predicate)
+    }
+
+    /// Creates an iterator that zips this iterator together with `other`, like
+    /// [`Iterator::zip()`], but keeps going until both have run out. Each element says whether it
+    /// holds values from both iterators or only from the one that hasn't run out yet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::{EitherOrBoth, IteratorExtensions};
+    ///
+    /// let zipped: Vec<_> = [1, 2, 3].iter().zip_longest(&['a']).collect();
+    ///
+    /// assert_eq!(
+    ///     zipped,
+    ///     [
+    ///         EitherOrBoth::Both(&1, &'a'),
+    ///         EitherOrBoth::Left(&2),
+    ///         EitherOrBoth::Left(&3)
+    ///     ]
+    /// );
+    /// ```
+    fn zip_longest<J>(self, other: J) -> ZipLongest<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator,
+    {
+        ZipLongest::new(self, other.into_iter())
+    }
+
+    /// Creates an iterator that yields the running total of this iterator's elements, i.e., its
+    /// prefix sums.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let sums: Vec<_> = [1, 2, 3, 4].iter().copied().cumulative_sum().collect();
+    ///
+    /// assert_eq!(sums, [1, 3, 6, 10]);
+    /// ```
+    fn cumulative_sum(self) -> CumulativeSum<Self>
+    where
+        Self: Sized,
+        Self::Item: Add<Output = Self::Item> + Clone,
+    {
+        CumulativeSum::new(self)
+    }
+
+    /// Creates an iterator that folds every element into an accumulator, like
+    /// [`Iterator::fold()`], and yields the accumulator after each step. The initial value `init`
+    /// itself isn't yielded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let words: Vec<_> = ["a", "b", "c"]
+    ///     .iter()
+    ///     .running_fold(String::new(), |mut acc, s| {
+    ///         acc.push_str(s);
+    ///         acc
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(words, ["a", "ab", "abc"]);
+    /// ```
+    fn running_fold<B, F>(self, init: B, f: F) -> RunningFold<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        RunningFold::new(self, init, f)
+    }
+
+    /// Creates an iterator that groups consecutive elements into [`Vec`]s, starting a new batch
+    /// between every two neighboring elements `prev` and `next` for which `predicate(prev, next)`
+    /// returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// // Timestamps, grouped into sessions separated by gaps of more than 30
+    /// let timestamps = [0, 10, 25, 100, 110, 200];
+    /// let sessions: Vec<_> = timestamps
+    ///     .iter()
+    ///     .split_when(|&&prev, &&next| next - prev > 30)
+    ///     .collect();
+    ///
+    /// assert_eq!(sessions, [vec![&0, &10, &25], vec![&100, &110], vec![&200]]);
+    /// ```
+    fn split_when<P>(self, predicate: P) -> SplitWhen<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        SplitWhen::new(self, predicate)
+    }
+
+    /// Creates an iterator that yields the elements that occur more than once, each one only the
+    /// first time it repeats.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let ids = [4, 1, 4, 2, 1, 4];
+    /// let duplicates: Vec<_> = ids.iter().duplicates().collect();
+    ///
+    /// assert_eq!(duplicates, [&4, &1]);
+    /// ```
+    fn duplicates(self) -> Duplicates<Self>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        Duplicates::new(self)
+    }
+
+    /// Creates an iterator that yields each distinct element only once, in the order they are
+    /// first seen. Unlike [`dedup_by_key()`](Self::dedup_by_key), this works for unsorted
+    /// iterators, at the cost of keeping a clone of every distinct element in a
+    /// [`HashSet`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let unique: Vec<_> = [3, 1, 3, 2, 1].iter().unique().collect();
+    ///
+    /// assert_eq!(unique, [&3, &1, &2]);
+    /// ```
+    fn unique(self) -> Unique<Self>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash + Clone,
+    {
+        Unique::new(self)
+    }
+
+    /// Creates an iterator that yields only the first element for each distinct key returned by
+    /// `key`, in the order they are first seen. See [`unique()`](Self::unique).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let words = ["apple", "avocado", "banana", "apricot", "blueberry"];
+    /// let first_per_letter: Vec<_> = words.iter().unique_by(|w| w.as_bytes()[0]).collect();
+    ///
+    /// assert_eq!(first_per_letter, [&"apple", &"banana"]);
+    /// ```
+    fn unique_by<F, K>(self, key: F) -> UniqueBy<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + Hash,
+    {
+        UniqueBy::new(self, key)
+    }
+
+    /// Creates an iterator that flattens the [`Ok`] values of an iterator of [`Result`]s whose
+    /// [`Ok`] values can be iterated over, while passing [`Err`] values through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let lines = ["1 2", "3 x", "4"];
+    /// let numbers: Vec<Result<Vec<u8>, _>> = lines
+    ///     .iter()
+    ///     .map(|line| line.split(' ').map(str::parse).collect())
+    ///     .collect();
+    ///
+    /// let flattened: Vec<_> = numbers.into_iter().flatten_ok().collect();
+    /// assert_eq!(flattened.len(), 4);
+    /// assert_eq!(flattened[..2], [Ok(1), Ok(2)]);
+    /// assert!(flattened[2].is_err());
+    /// assert_eq!(flattened[3], Ok(4));
+    /// ```
+    fn flatten_ok<T, E>(self) -> FlattenOk<Self, T>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        T: IntoIterator,
+    {
+        FlattenOk::new(self)
+    }
+
+    /// Creates an iterator that yields each element along with its [`Position`] in the iterator:
+    /// [`First`](Position::First), [`Middle`](Position::Middle), [`Last`](Position::Last), or
+    /// [`Only`](Position::Only) if there is just one element.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::{IteratorExtensions, Position};
+    ///
+    /// let mut list = String::new();
+    /// for (position, name) in ["Ann", "Bob", "Cid"].iter().with_position() {
+    ///     match position {
+    ///         Position::First | Position::Only => {}
+    ///         Position::Middle => list.push_str(", "),
+    ///         Position::Last => list.push_str(" and "),
+    ///     }
+    ///     list.push_str(name);
+    /// }
+    ///
+    /// assert_eq!(list, "Ann, Bob and Cid");
+    /// ```
+    fn with_position(self) -> WithPosition<Self>
+    where
+        Self: Sized,
+    {
+        WithPosition::new(self)
+    }
+
+    /// Creates an iterator that calls `f` with the zero-based index of and a reference to each
+    /// element as it passes through, like [`Iterator::inspect()`] with a built-in counter.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let mut log = vec![];
+    /// let total: i32 = [10, 20, 30, 40]
+    ///     .iter()
+    ///     .inspect_indexed(|i, n| {
+    ///         if i % 2 == 1 {
+    ///             log.push(format!("processed {} items, last was {}", i + 1, n));
+    ///         }
+    ///     })
+    ///     .sum();
+    ///
+    /// assert_eq!(total, 100);
+    /// assert_eq!(
+    ///     log,
+    ///     ["processed 2 items, last was 20", "processed 4 items, last was 40"]
+    /// );
+    /// ```
+    fn inspect_indexed<F>(self, f: F) -> InspectIndexed<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize, &Self::Item),
+    {
+        InspectIndexed::new(self, f)
+    }
+
+    /// Consumes an iterator of string-likes, joining all the non-empty ones with `separator`
+    /// between them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let (city, region, country) = ("Oslo", "", "Norway");
+    ///
+    /// assert_eq!([city, region, country].iter().join_non_empty(", "), "Oslo, Norway");
+    /// ```
+    fn join_non_empty(self, separator: &str) -> String
+    where
+        Self: Sized,
+        Self::Item: AsRef<str>,
+    {
+        let mut joined = String::new();
+        for item in self {
+            let item = item.as_ref();
+            if item.is_empty() {
+                continue;
+            }
+            if !joined.is_empty() {
+                joined.push_str(separator);
+            }
+            joined.push_str(item);
+        }
+
+        joined
+    }
+
+    /// Consumes an iterator of string-likes, joining them into a list the way you'd write one in
+    /// an English sentence, with `conjunction` (e.g., "and" or "or") before the last element and a
+    /// serial comma when there are more than two.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// assert_eq!(["tea"].iter().join_natural("or"), "tea");
+    /// assert_eq!(["tea", "coffee"].iter().join_natural("or"), "tea or coffee");
+    /// assert_eq!(
+    ///     ["tea", "coffee", "juice"].iter().join_natural("or"),
+    ///     "tea, coffee, or juice"
+    /// );
+    /// ```
+    fn join_natural(self, conjunction: &str) -> String
+    where
+        Self: Sized,
+        Self::Item: AsRef<str>,
+    {
+        let mut joined = String::new();
+        let mut has_middle = false;
+        for (position, item) in self.with_position() {
+            match position {
+                Position::First | Position::Only => {}
+                Position::Middle => {
+                    has_middle = true;
+                    joined.push_str(", ");
+                }
+                Position::Last => {
+                    joined.push_str(if has_middle { ", " } else { " " });
+                    joined.push_str(conjunction);
+                    joined.push(' ');
+                }
+            }
+            joined.push_str(item.as_ref());
+        }
+
+        joined
+    }
+}
+
+impl<T: ?Sized + Iterator> IteratorExtensions for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_max_by_key_calls_key_once_per_element() {
+        let mut calls = 0;
+        let min_max = [3, 1, 4, 1, 5].iter().min_max_by_key(|&&x| {
+            calls += 1;
+            x
+        });
+
+        assert_eq!(min_max, Some((&1, &5)));
+        assert_eq!(calls, 5);
+    }
+}
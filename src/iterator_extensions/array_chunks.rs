@@ -0,0 +1,101 @@
+use std::iter::{Flatten, FusedIterator};
+
+/// An iterator that yields the elements of another iterator in arrays of `N` elements each.
+///
+/// If the number of elements isn't a multiple of `N`, the elements left over at the end can be
+/// retrieved with [`ArrayChunks::into_remainder`] once the iterator has run out.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::array_chunks`](super::IteratorExtensions::array_chunks).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Option<[Option<I::Item>; N]>,
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, N> {
+    pub(super) fn new(iter: I) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        Self {
+            iter,
+            remainder: None,
+        }
+    }
+
+    /// Consumes this iterator, returning an iterator over the fewer than `N` elements that were
+    /// left over after the last full chunk. Before this iterator has run out, the returned
+    /// iterator is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ilyvion_util::iterator_extensions::IteratorExtensions;
+    ///
+    /// let mut chunks = IteratorExtensions::array_chunks::<2>(1..=5);
+    /// assert_eq!(chunks.by_ref().collect::<Vec<_>>(), [[1, 2], [3, 4]]);
+    /// assert_eq!(chunks.into_remainder().collect::<Vec<_>>(), [5]);
+    /// ```
+    pub fn into_remainder(self) -> Flatten<std::array::IntoIter<Option<I::Item>, N>> {
+        let remainder = self.remainder.unwrap_or_else(|| [(); N].map(|()| None));
+        IntoIterator::into_iter(remainder).flatten()
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_some() {
+            return None;
+        }
+
+        let mut chunk = [(); N].map(|()| None);
+        let mut filled = 0;
+        for (slot, x) in chunk.iter_mut().zip(&mut self.iter) {
+            *slot = Some(x);
+            filled += 1;
+        }
+
+        if filled < N {
+            self.remainder = Some(chunk);
+            None
+        } else {
+            Some(chunk.map(Option::unwrap))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remainder.is_some() {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|upper| upper / N))
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeIterator for ArrayChunks<I, N> {}
+
+impl<I: Iterator, const N: usize> FusedIterator for ArrayChunks<I, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remainder_is_empty_for_exact_multiples() {
+        let mut chunks = ArrayChunks::<_, 3>::new(0..6);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.by_ref().count(), 2);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder().count(), 0);
+    }
+
+    #[test]
+    fn remainder_is_empty_before_running_out() {
+        let mut chunks = ArrayChunks::<_, 3>::new(0..5);
+        assert_eq!(chunks.next(), Some([0, 1, 2]));
+        assert_eq!(chunks.into_remainder().count(), 0);
+    }
+}
@@ -0,0 +1,65 @@
+use std::iter::FusedIterator;
+
+/// An iterator that yields the elements of another iterator in groups of a fixed size, with the
+/// last group possibly being smaller.
+///
+/// This `struct` is created by [`IteratorExtensions::chunks`](super::IteratorExtensions::chunks).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I> Chunks<I> {
+    pub(super) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        Self { iter, size }
+    }
+}
+
+impl<I: Iterator> Iterator for Chunks<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<_> = self.iter.by_ref().take(self.size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.div_ceil(self.size),
+            upper.map(|upper| upper.div_ceil(self.size)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Chunks<I> {}
+
+impl<I: FusedIterator> FusedIterator for Chunks<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_hint_rounds_up() {
+        assert_eq!(Chunks::new(0..7, 3).len(), 3);
+        assert_eq!(
+            Chunks::new((0..7).filter(|_| true), 3).size_hint(),
+            (0, Some(3))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero")]
+    fn zero_size_panics() {
+        let _ = Chunks::new(0..7, 0);
+    }
+}
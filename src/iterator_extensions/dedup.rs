@@ -0,0 +1,83 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// An iterator that skips elements whose key is equal to the key of the element before it.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::dedup_by_key`](super::IteratorExtensions::dedup_by_key).
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupByKey<I, F, K> {
+    iter: I,
+    key: F,
+    last: Option<K>,
+}
+
+impl<I, F, K> DedupByKey<I, F, K> {
+    pub(super) fn new(iter: I, key: F) -> Self {
+        Self {
+            iter,
+            key,
+            last: None,
+        }
+    }
+}
+
+impl<I: fmt::Debug, F, K: fmt::Debug> fmt::Debug for DedupByKey<I, F, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupByKey")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<I, F, K> Iterator for DedupByKey<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for x in &mut self.iter {
+            let key = (self.key)(&x);
+            if self.last.as_ref() != Some(&key) {
+                self.last = Some(key);
+                return Some(x);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // If nothing has been yielded yet, the first element is always kept.
+        let lower = if self.last.is_none() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+impl<I, F, K> FusedIterator for DedupByKey<I, F, K>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_first_of_each_run() {
+        let mut deduped = DedupByKey::new([1, 1, 2, 2, 1].iter(), |n: &&i32| **n);
+        assert_eq!(deduped.size_hint(), (1, Some(5)));
+        assert_eq!(deduped.next(), Some(&1));
+        assert_eq!(deduped.size_hint(), (0, Some(4)));
+        assert_eq!(deduped.collect::<Vec<_>>(), [&2, &1]);
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+
+/// An iterator that yields the elements of another iterator that have been seen before, once per
+/// distinct element.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::duplicates`](super::IteratorExtensions::duplicates).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Duplicates<I: Iterator> {
+    iter: I,
+    /// Every element seen so far, along with whether it has been yielded as a duplicate yet.
+    seen: HashMap<I::Item, bool>,
+}
+
+impl<I: Iterator> Duplicates<I> {
+    pub(super) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<I> Iterator for Duplicates<I>
+where
+    I: Iterator,
+    I::Item: Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for x in &mut self.iter {
+            match self.seen.get_mut(&x) {
+                Some(yielded) if !*yielded => {
+                    *yielded = true;
+                    return Some(x);
+                }
+                Some(_) => {}
+                None => {
+                    self.seen.insert(x, false);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I> FusedIterator for Duplicates<I>
+where
+    I: FusedIterator,
+    I::Item: Eq + Hash,
+{
+}
@@ -0,0 +1,75 @@
+use std::iter::FusedIterator;
+
+/// An iterator that flattens the [`Ok`] values of an iterator of [`Result`]s, passing [`Err`]
+/// values through unchanged.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::flatten_ok`](super::IteratorExtensions::flatten_ok).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlattenOk<I, T: IntoIterator> {
+    iter: I,
+    inner: Option<T::IntoIter>,
+}
+
+impl<I, T: IntoIterator> FlattenOk<I, T> {
+    pub(super) fn new(iter: I) -> Self {
+        Self { iter, inner: None }
+    }
+}
+
+impl<I, T, E> Iterator for FlattenOk<I, T>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: IntoIterator,
+{
+    type Item = Result<T::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.inner.as_mut().and_then(Iterator::next) {
+                return Some(Ok(x));
+            }
+
+            match self.iter.next()? {
+                Ok(inner) => self.inner = Some(inner.into_iter()),
+                Err(e) => {
+                    self.inner = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let inner = self.inner.as_ref().map_or(0, |inner| inner.size_hint().0);
+        let upper = match self.iter.size_hint() {
+            (_, Some(0)) => self.inner.as_ref().map_or(Some(0), |i| i.size_hint().1),
+            _ => None,
+        };
+        (inner, upper)
+    }
+}
+
+impl<I, T, E> FusedIterator for FlattenOk<I, T>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    T: IntoIterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes_errors_through_in_order() {
+        let results = vec![Ok(vec![1, 2]), Err('x'), Ok(vec![]), Ok(vec![3])];
+        let mut flattened = FlattenOk::new(results.into_iter());
+
+        assert_eq!(flattened.size_hint(), (0, None));
+        assert_eq!(flattened.next(), Some(Ok(1)));
+        assert_eq!(flattened.size_hint(), (1, None));
+        assert_eq!(flattened.collect::<Vec<_>>(), [Ok(2), Err('x'), Ok(3)]);
+    }
+}
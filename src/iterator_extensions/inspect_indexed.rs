@@ -0,0 +1,64 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// An iterator that calls a closure with the index of and a reference to each element before
+/// yielding it.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::inspect_indexed`](super::IteratorExtensions::inspect_indexed).
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InspectIndexed<I, F> {
+    iter: I,
+    f: F,
+    index: usize,
+}
+
+impl<I, F> InspectIndexed<I, F> {
+    pub(super) fn new(iter: I, f: F) -> Self {
+        Self { iter, f, index: 0 }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for InspectIndexed<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectIndexed")
+            .field("iter", &self.iter)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for InspectIndexed<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize, &I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        (self.f)(self.index, &x);
+        self.index += 1;
+
+        Some(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for InspectIndexed<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(usize, &I::Item),
+{
+}
+
+impl<I, F> FusedIterator for InspectIndexed<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(usize, &I::Item),
+{
+}
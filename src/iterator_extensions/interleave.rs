@@ -0,0 +1,69 @@
+use std::iter::{Fuse, FusedIterator};
+
+/// An iterator that alternates between the elements of two iterators, continuing with the
+/// remaining elements of the longer one once the shorter one runs out.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::interleave`](super::IteratorExtensions::interleave).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Interleave<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    next_from_b: bool,
+}
+
+impl<I: Iterator, J: Iterator> Interleave<I, J> {
+    pub(super) fn new(a: I, b: J) -> Self {
+        Self {
+            a: a.fuse(),
+            b: b.fuse(),
+            next_from_b: false,
+        }
+    }
+}
+
+impl<I, J> Iterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_from_b = !self.next_from_b;
+        if self.next_from_b {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        (
+            a_lower.saturating_add(b_lower),
+            a_upper.zip(b_upper).and_then(|(a, b)| a.checked_add(b)),
+        )
+    }
+}
+
+impl<I, J> FusedIterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn continues_with_the_longer_iterator() {
+        let merged = Interleave::new(0..1, 10..13);
+        assert_eq!(merged.size_hint(), (4, Some(4)));
+        assert_eq!(merged.collect::<Vec<_>>(), [0, 10, 11, 12]);
+    }
+}
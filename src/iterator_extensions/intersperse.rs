@@ -0,0 +1,201 @@
+use std::fmt;
+use std::iter::{Fuse, FusedIterator};
+
+/// The state shared by [`Intersperse`] and [`IntersperseWith`], which only differ in how they
+/// produce their separators.
+#[derive(Debug, Clone)]
+struct Interspersed<I: Iterator> {
+    iter: Fuse<I>,
+    peeked: Option<I::Item>,
+    needs_separator: bool,
+}
+
+impl<I: Iterator> Interspersed<I> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.fuse(),
+            peeked: None,
+            needs_separator: false,
+        }
+    }
+
+    fn next_with<F: FnOnce() -> I::Item>(&mut self, separator: F) -> Option<I::Item> {
+        if self.needs_separator {
+            if self.peeked.is_none() {
+                self.peeked = self.iter.next();
+            }
+            self.peeked.as_ref()?;
+            self.needs_separator = false;
+            return Some(separator());
+        }
+
+        let x = self.peeked.take().or_else(|| self.iter.next())?;
+        self.needs_separator = true;
+        Some(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let peeked = usize::from(self.peeked.is_some());
+        // Every remaining element is preceded by a separator, except the very first one if no
+        // separator is due before it.
+        let len = |remaining: usize| match remaining {
+            0 => Some(0),
+            _ => remaining
+                .checked_mul(2)
+                .map(|n| n - usize::from(!self.needs_separator)),
+        };
+
+        (
+            len(lower.saturating_add(peeked)).unwrap_or(usize::MAX),
+            upper
+                .and_then(|upper| upper.checked_add(peeked))
+                .and_then(len),
+        )
+    }
+}
+
+/// An iterator that places a clone of a separator between the elements of another iterator.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::intersperse`](super::IteratorExtensions::intersperse).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: Iterator> {
+    inner: Interspersed<I>,
+    separator: I::Item,
+}
+
+impl<I: Iterator> Intersperse<I> {
+    pub(super) fn new(iter: I, separator: I::Item) -> Self {
+        Self {
+            inner: Interspersed::new(iter),
+            separator,
+        }
+    }
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let separator = &self.separator;
+        self.inner.next_with(|| separator.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I> FusedIterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+/// An iterator that places separators produced by a closure between the elements of another
+/// iterator.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::intersperse_with`](super::IteratorExtensions::intersperse_with).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseWith<I: Iterator, F> {
+    inner: Interspersed<I>,
+    separator: F,
+}
+
+impl<I: Iterator, F> IntersperseWith<I, F> {
+    pub(super) fn new(iter: I, separator: F) -> Self {
+        Self {
+            inner: Interspersed::new(iter),
+            separator,
+        }
+    }
+}
+
+impl<I, F> Clone for IntersperseWith<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            separator: self.separator.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for IntersperseWith<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntersperseWith")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for IntersperseWith<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let separator = &mut self.separator;
+        self.inner.next_with(separator)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for IntersperseWith<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> I::Item,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_hint_counts_separators() {
+        let mut interspersed = Intersperse::new(0..3, -1);
+        assert_eq!(interspersed.size_hint(), (5, Some(5)));
+        assert_eq!(interspersed.next(), Some(0));
+        assert_eq!(interspersed.size_hint(), (4, Some(4)));
+        assert_eq!(interspersed.next(), Some(-1));
+        assert_eq!(interspersed.size_hint(), (3, Some(3)));
+        assert_eq!(interspersed.collect::<Vec<_>>(), [1, -1, 2]);
+
+        assert_eq!(Intersperse::new(0..0, -1).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn separators_are_only_produced_between_elements() {
+        let mut count = 0;
+        let interspersed: Vec<_> = IntersperseWith::new(0..1, || {
+            count += 1;
+            -1
+        })
+        .collect();
+
+        assert_eq!(interspersed, [0]);
+        assert_eq!(count, 0);
+    }
+}
@@ -0,0 +1,168 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+/// The state shared between the two halves of a lazy partition.
+struct Shared<I, P, L, R, LT, RT> {
+    iter: I,
+    predicate: P,
+    left_map: L,
+    right_map: R,
+    left: VecDeque<LT>,
+    right: VecDeque<RT>,
+}
+
+impl<I, P, L, R, LT, RT> Shared<I, P, L, R, LT, RT>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+    L: FnMut(I::Item) -> LT,
+    R: FnMut(I::Item) -> RT,
+{
+    fn next_left(&mut self) -> Option<LT> {
+        if let Some(x) = self.left.pop_front() {
+            return Some(x);
+        }
+
+        for x in &mut self.iter {
+            if (self.predicate)(&x) {
+                return Some((self.left_map)(x));
+            }
+            self.right.push_back((self.right_map)(x));
+        }
+
+        None
+    }
+
+    fn next_right(&mut self) -> Option<RT> {
+        if let Some(x) = self.right.pop_front() {
+            return Some(x);
+        }
+
+        for x in &mut self.iter {
+            if !(self.predicate)(&x) {
+                return Some((self.right_map)(x));
+            }
+            self.left.push_back((self.left_map)(x));
+        }
+
+        None
+    }
+}
+
+macro_rules! partition_half {
+    ($(#[$attr:meta])* $name:ident, $item:ident, $next:ident, $buffer:ident) => {
+        $(#[$attr])*
+        #[must_use = "iterators are lazy and do nothing unless consumed"]
+        pub struct $name<I, P, L, R, LT, RT> {
+            shared: Rc<RefCell<Shared<I, P, L, R, LT, RT>>>,
+        }
+
+        impl<I, P, L, R, LT, RT> fmt::Debug for $name<I, P, L, R, LT, RT>
+        where
+            I: fmt::Debug,
+            LT: fmt::Debug,
+            RT: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let shared = self.shared.borrow();
+                f.debug_struct(stringify!($name))
+                    .field("iter", &shared.iter)
+                    .field("buffered", &shared.$buffer)
+                    .finish()
+            }
+        }
+
+        impl<I, P, L, R, LT, RT> Iterator for $name<I, P, L, R, LT, RT>
+        where
+            I: Iterator,
+            P: FnMut(&I::Item) -> bool,
+            L: FnMut(I::Item) -> LT,
+            R: FnMut(I::Item) -> RT,
+        {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.shared.borrow_mut().$next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let shared = self.shared.borrow();
+                let buffered = shared.$buffer.len();
+                let upper = shared.iter.size_hint().1;
+                (buffered, upper.and_then(|upper| upper.checked_add(buffered)))
+            }
+        }
+    };
+}
+
+partition_half!(
+    /// The half of a lazy partition that yields the mapped elements for which the predicate
+    /// returned `true`.
+    ///
+    /// This `struct` is created by
+    /// [`IteratorExtensions::partition_map_lazy`](super::IteratorExtensions::partition_map_lazy).
+    PartitionLeft,
+    LT,
+    next_left,
+    left
+);
+
+partition_half!(
+    /// The half of a lazy partition that yields the mapped elements for which the predicate
+    /// returned `false`.
+    ///
+    /// This `struct` is created by
+    /// [`IteratorExtensions::partition_map_lazy`](super::IteratorExtensions::partition_map_lazy).
+    PartitionRight,
+    RT,
+    next_right,
+    right
+);
+
+#[allow(clippy::type_complexity)]
+pub(super) fn partition_map_lazy<I, P, L, R, LT, RT>(
+    iter: I,
+    predicate: P,
+    left_map: L,
+    right_map: R,
+) -> (
+    PartitionLeft<I, P, L, R, LT, RT>,
+    PartitionRight<I, P, L, R, LT, RT>,
+) {
+    let shared = Rc::new(RefCell::new(Shared {
+        iter,
+        predicate,
+        left_map,
+        right_map,
+        left: VecDeque::new(),
+        right: VecDeque::new(),
+    }));
+
+    (
+        PartitionLeft {
+            shared: Rc::clone(&shared),
+        },
+        PartitionRight { shared },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::IteratorExtensions;
+
+    #[test]
+    fn buffers_only_the_unconsumed_side() {
+        let (mut even, mut odd) = (1..=6).partition_map_lazy(|n| n % 2 == 0, |n| n * 10, |n| -n);
+
+        assert_eq!(even.next(), Some(20));
+        assert_eq!(odd.size_hint(), (1, Some(5)));
+        assert_eq!(even.size_hint(), (0, Some(4)));
+        assert_eq!(even.next(), Some(40));
+        assert_eq!(odd.by_ref().collect::<Vec<_>>(), [-1, -3, -5]);
+        assert_eq!(even.size_hint(), (1, Some(1)));
+        assert_eq!(even.next(), Some(60));
+        assert_eq!(even.next(), None);
+    }
+}
@@ -0,0 +1,91 @@
+use std::iter::FusedIterator;
+
+/// An iterator over every pair of an element from one iterator and an element from another.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::cartesian_product`](super::IteratorExtensions::cartesian_product).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CartesianProduct<I: Iterator, J> {
+    a: I,
+    current: Option<I::Item>,
+    b: J,
+    b_original: J,
+}
+
+impl<I: Iterator, J: Clone> CartesianProduct<I, J> {
+    pub(super) fn new(a: I, b: J) -> Self {
+        Self {
+            a,
+            current: None,
+            b: b.clone(),
+            b_original: b,
+        }
+    }
+}
+
+impl<I, J> Iterator for CartesianProduct<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let current = match &self.current {
+                Some(current) => current,
+                None => self.current.insert(self.a.next()?),
+            };
+            if let Some(b) = self.b.next() {
+                return Some((current.clone(), b));
+            }
+
+            self.current = None;
+            self.b = self.b_original.clone();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let (full_lower, full_upper) = self.b_original.size_hint();
+        let in_progress = usize::from(self.current.is_some());
+
+        let lower = a_lower
+            .saturating_mul(full_lower)
+            .saturating_add(b_lower * in_progress);
+        let upper = a_upper
+            .zip(full_upper)
+            .and_then(|(a, full)| a.checked_mul(full))
+            .zip(b_upper)
+            .and_then(|(rest, b)| rest.checked_add(b * in_progress));
+        (lower, upper)
+    }
+}
+
+impl<I, J> FusedIterator for CartesianProduct<I, J>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_hint_tracks_progress() {
+        let mut pairs = CartesianProduct::new(0..3, 0..4);
+        assert_eq!(pairs.size_hint(), (12, Some(12)));
+        pairs.nth(4);
+        assert_eq!(pairs.size_hint(), (7, Some(7)));
+        assert_eq!(pairs.by_ref().count(), 7);
+        assert_eq!(pairs.size_hint(), (0, Some(0)));
+
+        assert_eq!(CartesianProduct::new(0..3, 0..0).count(), 0);
+    }
+}
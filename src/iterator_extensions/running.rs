@@ -0,0 +1,139 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Add;
+
+/// An iterator that yields the running total of the elements of another iterator.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::cumulative_sum`](super::IteratorExtensions::cumulative_sum).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CumulativeSum<I: Iterator> {
+    iter: I,
+    sum: Option<I::Item>,
+}
+
+impl<I: Iterator> CumulativeSum<I> {
+    pub(super) fn new(iter: I) -> Self {
+        Self { iter, sum: None }
+    }
+}
+
+impl<I> Iterator for CumulativeSum<I>
+where
+    I: Iterator,
+    I::Item: Add<Output = I::Item> + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        let sum = match self.sum.take() {
+            Some(sum) => sum + x,
+            None => x,
+        };
+        self.sum = Some(sum.clone());
+
+        Some(sum)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for CumulativeSum<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Add<Output = I::Item> + Clone,
+{
+}
+
+impl<I> FusedIterator for CumulativeSum<I>
+where
+    I: FusedIterator,
+    I::Item: Add<Output = I::Item> + Clone,
+{
+}
+
+/// An iterator that yields every intermediate accumulator of folding another iterator.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::running_fold`](super::IteratorExtensions::running_fold).
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningFold<I, B, F> {
+    iter: I,
+    accumulator: Option<B>,
+    f: F,
+}
+
+impl<I, B, F> RunningFold<I, B, F> {
+    pub(super) fn new(iter: I, init: B, f: F) -> Self {
+        Self {
+            iter,
+            accumulator: Some(init),
+            f,
+        }
+    }
+}
+
+impl<I: fmt::Debug, B: fmt::Debug, F> fmt::Debug for RunningFold<I, B, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningFold")
+            .field("iter", &self.iter)
+            .field("accumulator", &self.accumulator)
+            .finish()
+    }
+}
+
+impl<I, B, F> Iterator for RunningFold<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        let accumulator = (self.f)(self.accumulator.take()?, x);
+        self.accumulator = Some(accumulator.clone());
+
+        Some(accumulator)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.accumulator.is_some() {
+            self.iter.size_hint()
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<I, B, F> FusedIterator for RunningFold<I, B, F>
+where
+    I: FusedIterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cumulative_sum_of_empty_iterator_is_empty() {
+        assert_eq!(CumulativeSum::new(0..0).next(), None);
+        assert_eq!(CumulativeSum::new(1..4).len(), 3);
+    }
+
+    #[test]
+    fn running_fold_yields_each_accumulator() {
+        let maxima: Vec<_> =
+            RunningFold::new([3, 1, 4, 1, 5].iter(), 0, |acc: i32, &n| acc.max(n)).collect();
+        assert_eq!(maxima, [3, 3, 4, 4, 5]);
+    }
+}
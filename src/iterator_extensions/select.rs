@@ -0,0 +1,66 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// Pairs an element with the key it is ordered by, ignoring the element itself when comparing.
+pub(super) struct Keyed<K, T> {
+    pub(super) key: K,
+    pub(super) item: T,
+}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Returns the `k` largest elements of `iter` in descending order, keeping no more than `k`
+/// elements in memory at a time.
+pub(super) fn largest<I>(iter: I, k: usize) -> Vec<I::Item>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    if k == 0 {
+        return vec![];
+    }
+
+    // A min-heap of the largest elements seen so far, so the smallest of them is the one evicted.
+    let mut heap = BinaryHeap::with_capacity(k);
+    for x in iter {
+        if heap.len() < k {
+            heap.push(Reverse(x));
+        } else if let Some(mut smallest) = heap.peek_mut() {
+            if x > smallest.0 {
+                smallest.0 = x;
+            }
+        }
+    }
+
+    heap.into_sorted_vec().into_iter().map(|x| x.0).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_largest_elements() {
+        assert_eq!(largest([5, 1, 9, 3, 7, 9].iter(), 3), [&9, &9, &7]);
+        assert_eq!(largest(0..2, 5), [1, 0]);
+        assert!(largest(0..2, 0).is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// An iterator that groups consecutive elements of another iterator into batches, starting a new
+/// batch between every two elements for which a predicate returns `true`.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::split_when`](super::IteratorExtensions::split_when).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitWhen<I: Iterator, P> {
+    iter: I,
+    pending: Option<I::Item>,
+    predicate: P,
+}
+
+impl<I: Iterator, P> SplitWhen<I, P> {
+    pub(super) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            iter,
+            pending: None,
+            predicate,
+        }
+    }
+}
+
+impl<I, P> Clone for SplitWhen<I, P>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            pending: self.pending.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<I, P> fmt::Debug for SplitWhen<I, P>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitWhen")
+            .field("iter", &self.iter)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for SplitWhen<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.pending.take().or_else(|| self.iter.next())?;
+        let mut batch = vec![first];
+
+        for x in &mut self.iter {
+            if (self.predicate)(&batch[batch.len() - 1], &x) {
+                self.pending = Some(x);
+                break;
+            }
+            batch.push(x);
+        }
+
+        Some(batch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let pending = usize::from(self.pending.is_some());
+        (
+            usize::from(lower > 0 || pending > 0),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl<I, P> FusedIterator for SplitWhen<I, P>
+where
+    I: FusedIterator,
+    P: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_between_elements() {
+        let mut batches = SplitWhen::new([1, 2, 3, 7, 8, 20].iter(), |a: &&i32, b: &&i32| {
+            **b - **a > 2
+        });
+        assert_eq!(batches.size_hint(), (1, Some(6)));
+        assert_eq!(batches.next(), Some(vec![&1, &2, &3]));
+        assert_eq!(batches.next(), Some(vec![&7, &8]));
+        assert_eq!(batches.size_hint(), (1, Some(1)));
+        assert_eq!(batches.next(), Some(vec![&20]));
+        assert_eq!(batches.next(), None);
+    }
+
+    #[test]
+    fn size_hint_of_infinite_iterator_does_not_overflow() {
+        let source = std::iter::once(0).chain(std::iter::repeat(1));
+        let mut batches = SplitWhen::new(source, |a: &i32, b: &i32| a != b);
+        assert_eq!(batches.next(), Some(vec![0]));
+        assert_eq!(batches.size_hint(), (1, None));
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// An iterator that yields elements while a predicate holds, along with the first element for
+/// which it doesn't.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::take_while_inclusive`](super::IteratorExtensions::take_while_inclusive).
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeWhileInclusive<I, P> {
+    iter: I,
+    predicate: P,
+    done: bool,
+}
+
+impl<I, P> TakeWhileInclusive<I, P> {
+    pub(super) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            iter,
+            predicate,
+            done: false,
+        }
+    }
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for TakeWhileInclusive<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeWhileInclusive")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for TakeWhileInclusive<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let x = self.iter.next()?;
+        if !(self.predicate)(&x) {
+            self.done = true;
+        }
+        Some(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, P> FusedIterator for TakeWhileInclusive<I, P>
+where
+    I: FusedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_after_the_first_failing_element() {
+        let mut taken = TakeWhileInclusive::new([1, 2, 5, 1, 2].iter(), |n: &&i32| **n < 3);
+        assert_eq!(taken.by_ref().collect::<Vec<_>>(), [&1, &2, &5]);
+        assert_eq!(taken.size_hint(), (0, Some(0)));
+        assert_eq!(taken.next(), None);
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FusedIterator;
+
+/// An iterator that yields each distinct element of another iterator once, in the order they were
+/// first seen.
+///
+/// This `struct` is created by [`IteratorExtensions::unique`](super::IteratorExtensions::unique).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Unique<I: Iterator> {
+    iter: I,
+    seen: HashSet<I::Item>,
+}
+
+impl<I: Iterator> Unique<I> {
+    pub(super) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<I> Iterator for Unique<I>
+where
+    I: Iterator,
+    I::Item: Eq + Hash + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let seen = &mut self.seen;
+        self.iter
+            .find(|x| !seen.contains(x) && seen.insert(x.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (usize::from(lower > 0 && self.seen.is_empty()), upper)
+    }
+}
+
+impl<I> FusedIterator for Unique<I>
+where
+    I: FusedIterator,
+    I::Item: Eq + Hash + Clone,
+{
+}
+
+/// An iterator that yields the elements of another iterator whose key hasn't been seen before.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::unique_by`](super::IteratorExtensions::unique_by).
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UniqueBy<I, F, K> {
+    iter: I,
+    key: F,
+    seen: HashSet<K>,
+}
+
+impl<I, F, K> UniqueBy<I, F, K> {
+    pub(super) fn new(iter: I, key: F) -> Self {
+        Self {
+            iter,
+            key,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<I: fmt::Debug, F, K: fmt::Debug> fmt::Debug for UniqueBy<I, F, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniqueBy")
+            .field("iter", &self.iter)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<I, F, K> Iterator for UniqueBy<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, key, seen } = self;
+        iter.find(|x| seen.insert(key(x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (usize::from(lower > 0 && self.seen.is_empty()), upper)
+    }
+}
+
+impl<I, F, K> FusedIterator for UniqueBy<I, F, K>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> K,
+    K: Eq + Hash,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_first_element_is_guaranteed() {
+        let mut unique = Unique::new([1, 1, 1].iter());
+        assert_eq!(unique.size_hint(), (1, Some(3)));
+        assert_eq!(unique.next(), Some(&1));
+        assert_eq!(unique.size_hint(), (0, Some(2)));
+        assert_eq!(unique.next(), None);
+    }
+}
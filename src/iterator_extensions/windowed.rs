@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+/// An iterator that yields overlapping windows of consecutive elements of another iterator.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::windowed`](super::IteratorExtensions::windowed).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windowed<I: Iterator> {
+    iter: I,
+    window: VecDeque<I::Item>,
+    size: usize,
+}
+
+impl<I: Iterator> Windowed<I> {
+    pub(super) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+
+        Self {
+            iter,
+            window: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Windowed<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        Some(self.window.iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // Every element still to come completes one more window, once the first window is full.
+        let missing = self.size - self.window.len().min(self.size - 1);
+        (
+            lower.saturating_add(1).saturating_sub(missing),
+            upper
+                .and_then(|upper| upper.checked_add(1))
+                .map(|upper| upper.saturating_sub(missing)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Windowed<I> where I::Item: Clone {}
+
+impl<I: FusedIterator> FusedIterator for Windowed<I> where I::Item: Clone {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_hint_counts_remaining_windows() {
+        let mut windows = Windowed::new(0..5, 3);
+        assert_eq!(windows.len(), 3);
+        windows.next();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows.by_ref().count(), 2);
+        assert_eq!(windows.len(), 0);
+
+        assert_eq!(Windowed::new(0..2, 3).len(), 0);
+        assert_eq!(Windowed::new(0..2, 3).next(), None);
+    }
+
+    #[test]
+    fn size_hint_of_infinite_iterator_does_not_overflow() {
+        let (lower, upper) = Windowed::new(std::iter::repeat(1), 2).size_hint();
+        assert!(lower >= usize::MAX - 2);
+        assert_eq!(upper, None);
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt;
+use std::iter::{FusedIterator, Peekable};
+
+/// The position of an element within an iterator, as yielded by [`WithPosition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The first of several elements.
+    First,
+    /// Neither the first nor the last element.
+    Middle,
+    /// The last of several elements.
+    Last,
+    /// The only element.
+    Only,
+}
+
+/// An iterator that yields the elements of another iterator along with their [`Position`].
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::with_position`](super::IteratorExtensions::with_position).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WithPosition<I: Iterator> {
+    iter: Peekable<I>,
+    started: bool,
+}
+
+impl<I: Iterator> WithPosition<I> {
+    pub(super) fn new(iter: I) -> Self {
+        Self {
+            iter: iter.peekable(),
+            started: false,
+        }
+    }
+}
+
+impl<I> Clone for WithPosition<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            started: self.started,
+        }
+    }
+}
+
+impl<I> fmt::Debug for WithPosition<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithPosition")
+            .field("iter", &self.iter)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for WithPosition<I> {
+    type Item = (Position, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        let is_last = self.iter.peek().is_none();
+        let position = match (self.started, is_last) {
+            (false, true) => Position::Only,
+            (false, false) => Position::First,
+            (true, false) => Position::Middle,
+            (true, true) => Position::Last,
+        };
+        self.started = true;
+
+        Some((position, x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for WithPosition<I> {}
+
+impl<I: FusedIterator> FusedIterator for WithPosition<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_all_positions() {
+        let positions: Vec<_> = WithPosition::new(0..4).map(|(p, _)| p).collect();
+        assert_eq!(
+            positions,
+            [
+                Position::First,
+                Position::Middle,
+                Position::Middle,
+                Position::Last
+            ]
+        );
+
+        assert_eq!(WithPosition::new(0..1).next(), Some((Position::Only, 0)));
+        assert_eq!(WithPosition::new(0..0).next(), None);
+    }
+}
@@ -0,0 +1,97 @@
+use std::iter::{Fuse, FusedIterator};
+
+/// A value from one or both of two zipped iterators.
+///
+/// This is the item type of [`ZipLongest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    /// Both iterators yielded a value.
+    Both(A, B),
+    /// Only the left iterator yielded a value; the right one has run out.
+    Left(A),
+    /// Only the right iterator yielded a value; the left one has run out.
+    Right(B),
+}
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// Returns the left value, if there is one.
+    pub fn left(self) -> Option<A> {
+        match self {
+            Self::Both(a, _) | Self::Left(a) => Some(a),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if there is one.
+    pub fn right(self) -> Option<B> {
+        match self {
+            Self::Both(_, b) | Self::Right(b) => Some(b),
+            Self::Left(_) => None,
+        }
+    }
+}
+
+/// An iterator that zips two iterators together, continuing until both have run out.
+///
+/// This `struct` is created by
+/// [`IteratorExtensions::zip_longest`](super::IteratorExtensions::zip_longest).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipLongest<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+}
+
+impl<I: Iterator, J: Iterator> ZipLongest<I, J> {
+    pub(super) fn new(a: I, b: J) -> Self {
+        Self {
+            a: a.fuse(),
+            b: b.fuse(),
+        }
+    }
+}
+
+impl<I: Iterator, J: Iterator> Iterator for ZipLongest<I, J> {
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+            (Some(a), None) => Some(EitherOrBoth::Left(a)),
+            (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        (
+            a_lower.max(b_lower),
+            a_upper.zip(b_upper).map(|(a, b)| a.max(b)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator, J: ExactSizeIterator> ExactSizeIterator for ZipLongest<I, J> {}
+
+impl<I: Iterator, J: Iterator> FusedIterator for ZipLongest<I, J> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn either_or_both_accessors() {
+        assert_eq!(EitherOrBoth::<_, ()>::Left(1).left(), Some(1));
+        assert_eq!(EitherOrBoth::<_, ()>::Left(1).right(), None);
+        assert_eq!(EitherOrBoth::Both(1, 'a').right(), Some('a'));
+        assert_eq!(EitherOrBoth::<(), _>::Right('a').left(), None);
+    }
+
+    #[test]
+    fn length_is_that_of_the_longer_iterator() {
+        assert_eq!(ZipLongest::new(0..2, 0..5).len(), 5);
+        assert_eq!(ZipLongest::new(0..7, 0..5).len(), 7);
+    }
+}
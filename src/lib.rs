@@ -27,7 +27,7 @@
 // Deny (don't do this)
 #![deny(clippy::cast_lossless)]
 #![deny(clippy::default_trait_access)]
-#![deny(clippy::empty_enum)]
+#![deny(clippy::empty_enums)]
 #![deny(clippy::enum_glob_use)]
 #![deny(clippy::expl_impl_clone_on_copy)]
 #![deny(clippy::explicit_into_iter_loop)]
@@ -65,6 +65,7 @@
 // </editor-fold>
 
 pub mod cache;
+pub mod collections;
 pub mod color;
 pub mod file;
 pub mod float_extensions;
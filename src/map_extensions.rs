@@ -1,33 +1,32 @@
-//! Various [`HashMap`] and [`BTreeMap`] extensions
+//! Various [`HashMap`] and [`BTreeMap`] extensions, along with the corresponding [`HashSet`] and
+//! [`BTreeSet`] extensions
 
 use paste::paste;
 use std::borrow::Borrow;
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{btree_map, BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::{Bound, RangeBounds};
 //use std::mem::ManuallyDrop;
 
 macro_rules! tuple_borrowing {
     ($($ty:ident) +) => {
         paste! {
-            trait [< HashTupleBorrow $($ty)+ >]<$($ty,)+> {
+            trait [< HashTupleBorrow $($ty)+ >]<$($ty: ?Sized,)+> {
                 fn tuple(&self) -> ($(&$ty,)+);
             }
-            impl<$($ty,)+> [< HashTupleBorrow $($ty)+ >]<$($ty,)+> for ($($ty,)+) {
-                #[allow(non_snake_case)]
-                fn tuple(&self) -> ($(&$ty,)+) {
-                    let ($([< entry $ty >],)+) = self;
-                    ($([< entry $ty >],)+)
-                }
-            }
-            impl<$($ty,)+> [< HashTupleBorrow $($ty)+ >]<$($ty,)+> for ($(&'_ $ty,)+) {
+            impl<$([< K $ty >],)+ $($ty: ?Sized,)+> [< HashTupleBorrow $($ty)+ >]<$($ty,)+> for ($([< K $ty >],)+)
+            where
+                $([< K $ty >]: Borrow<$ty>,)+
+            {
                 #[allow(non_snake_case)]
                 fn tuple(&self) -> ($(&$ty,)+) {
                     let ($([< entry $ty >],)+) = self;
-                    ($([< entry $ty >],)+)
+                    ($([< entry $ty >].borrow(),)+)
                 }
             }
-            impl<'r, $($ty,)+> Hash for dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>
+            impl<'r, $($ty: ?Sized,)+> Hash for dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>
             where
                 $($ty: 'r + Hash,)+
             {
@@ -35,7 +34,7 @@ macro_rules! tuple_borrowing {
                     self.tuple().hash(h);
                 }
             }
-            impl<'r, $($ty,)+> PartialEq for dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>
+            impl<'r, $($ty: ?Sized,)+> PartialEq for dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>
             where
                 $($ty: 'r + Eq ,)+
             {
@@ -43,13 +42,14 @@ macro_rules! tuple_borrowing {
                     self.tuple() == other.tuple()
                 }
             }
-            impl<'r, $($ty,)+> Eq for dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>
+            impl<'r, $($ty: ?Sized,)+> Eq for dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>
             where
                 $($ty: 'r + Eq ,)+
             {
             }
-            impl<'r, $($ty,)+> Borrow<dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>> for ($($ty,)+)
+            impl<'r, $([< K $ty >],)+ $($ty: ?Sized,)+> Borrow<dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>> for ($([< K $ty >],)+)
             where
+                $([< K $ty >]: 'r + Borrow<$ty>,)+
                 $($ty: 'r + Eq + Hash,)+
             {
                 fn borrow(&self) -> &(dyn 'r + [< HashTupleBorrow $($ty)+ >]<$($ty,)+>) {
@@ -58,24 +58,20 @@ macro_rules! tuple_borrowing {
             }
 
 
-            trait [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> {
+            trait [< OrdTupleBorrow $($ty)+ >]<$($ty: ?Sized,)+> {
                 fn tuple(&self) -> ($(&$ty,)+);
             }
-            impl<$($ty,)+> [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> for ($($ty,)+) {
-                #[allow(non_snake_case)]
-                fn tuple(&self) -> ($(&$ty,)+) {
-                    let ($([< entry $ty >],)+) = self;
-                    ($([< entry $ty >],)+)
-                }
-            }
-            impl<$($ty,)+> [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> for ($(&'_ $ty,)+) {
+            impl<$([< K $ty >],)+ $($ty: ?Sized,)+> [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> for ($([< K $ty >],)+)
+            where
+                $([< K $ty >]: Borrow<$ty>,)+
+            {
                 #[allow(non_snake_case)]
                 fn tuple(&self) -> ($(&$ty,)+) {
                     let ($([< entry $ty >],)+) = self;
-                    ($([< entry $ty >],)+)
+                    ($([< entry $ty >].borrow(),)+)
                 }
             }
-            impl<'r, $($ty,)+> PartialEq for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
+            impl<'r, $($ty: ?Sized,)+> PartialEq for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
             where
                 $($ty: 'r + Ord ,)+
             {
@@ -83,20 +79,20 @@ macro_rules! tuple_borrowing {
                     self.tuple() == other.tuple()
                 }
             }
-            impl<'r, $($ty,)+> Eq for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
+            impl<'r, $($ty: ?Sized,)+> Eq for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
             where
                 $($ty: 'r + Ord ,)+
             {
             }
-            impl<'r, $($ty,)+> PartialOrd for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
+            impl<'r, $($ty: ?Sized,)+> PartialOrd for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
             where
                 $($ty: 'r + Ord,)+
             {
                 fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-                    self.tuple().partial_cmp(&other.tuple())
+                    Some(self.cmp(other))
                 }
             }
-            impl<'r, $($ty,)+> Ord for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
+            impl<'r, $($ty: ?Sized,)+> Ord for dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>
             where
                 $($ty: 'r + Ord,)+
             {
@@ -104,8 +100,9 @@ macro_rules! tuple_borrowing {
                     self.tuple().cmp(&other.tuple())
                 }
             }
-            impl<'r, $($ty,)+> Borrow<dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>> for ($($ty,)+)
+            impl<'r, $([< K $ty >],)+ $($ty: ?Sized,)+> Borrow<dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>> for ($([< K $ty >],)+)
             where
+                $([< K $ty >]: 'r + Borrow<$ty>,)+
                 $($ty: 'r + Ord,)+
             {
                 fn borrow(&self) -> &(dyn 'r + [< OrdTupleBorrow $($ty)+ >]<$($ty,)+>) {
@@ -115,12 +112,33 @@ macro_rules! tuple_borrowing {
 
             /// The method provided by this extension trait allows you to look up values
             /// in a map with a tuple key — i.e.
-            #[doc = ::std::concat!("`", ::std::stringify!(($($ty),+)), "`")]
+            #[doc = ::std::concat!("`", ::std::stringify!(($([< K $ty >]),+)), "`")]
             /// — by utilizing a trick where you essentially have
-            #[doc = ::std::concat!("`Borrow<", ::std::stringify!(($(&$ty),+)), "> for ", ::std::stringify!(($($ty),+)), "`")]
-            /// letting us look up a tuple key with owned values using a tuple of the same
-            /// types with borrowed values.
-            pub trait [< MapTupleExtensions $($ty)+ >]<$($ty,)+ Val> {
+            #[doc = ::std::concat!("`Borrow<", ::std::stringify!(($(&$ty),+)), "> for ", ::std::stringify!(($([< K $ty >]),+)), "`")]
+            /// whenever each key component implements `Borrow` of the corresponding type,
+            /// letting us look up a tuple key with owned values using a tuple of borrowed
+            /// values. Just like with the map's own lookup methods, the borrowed values don't
+            /// need to be of the same types as the owned ones, so e.g. a `(String, u32)` key can
+            /// be looked up using a `(&str, &u32)`.
+            ///
+            /// # Examples
+            ///
+            /// Because the trait implementations are macro generated, the example will only
+            /// use a specific tuple size, but it works the same for all tuples up to arity 10.
+            ///
+            /// ```
+            /// use std::collections::{BTreeMap, HashMap};
+            /// use ilyvion_util::map_extensions::MapTupleExtensionsTU;
+            ///
+            /// let mut hash_map = HashMap::new();
+            /// hash_map.insert((String::from("alice"), 2024_u32), 10);
+            /// assert_eq!(Some(&10), hash_map.get_by_tuple(("alice", &2024)));
+            ///
+            /// let mut tree_map = BTreeMap::new();
+            /// tree_map.insert((vec![1_u8, 2], String::from("bytes")), 20);
+            /// assert_eq!(Some(&20), tree_map.get_by_tuple((&[1, 2][..], "bytes")));
+            /// ```
+            pub trait [< MapTupleExtensions $($ty)+ >]<$($ty: ?Sized,)+ Val> {
                 /// Look up a value in a map using a tuple of owned values as a key by using
                 /// a tuple of borrowed values.
                 ///
@@ -191,10 +209,261 @@ macro_rules! tuple_borrowing {
                 /// ```
                 #[allow(clippy::too_many_arguments)]
                 fn get_by_tuple_entries(&self, $([< entry_ $ty:lower >]: &$ty,)+) -> Option<&Val>;
+
+                /// Look up a value in a map for modification using a tuple of owned values as a
+                /// key by using a tuple of borrowed values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut hash_map = HashMap::new();
+                /// hash_map.insert((16_i32, 32_u8, String::from("Hello, world!")), 1);
+                ///
+                /// if let Some(hits) = hash_map.get_mut_by_tuple((&16, &32, &String::from("Hello, world!"))) {
+                ///     *hits += 1;
+                /// }
+                /// assert_eq!(Some(&2), hash_map.get_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut tree_map = BTreeMap::new();
+                /// tree_map.insert((16_i32, 32_u8, String::from("Hello, world!")), 1);
+                ///
+                /// if let Some(hits) = tree_map.get_mut_by_tuple((&16, &32, &String::from("Hello, world!"))) {
+                ///     *hits += 1;
+                /// }
+                /// assert_eq!(Some(&2), tree_map.get_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// ```
+                fn get_mut_by_tuple(&mut self, ($([< entry_ $ty:lower >],)+): ($(&$ty,)+)) -> Option<&mut Val> {
+                    self.get_mut_by_tuple_entries($([< entry_ $ty:lower >],)+)
+                }
+
+                /// Look up a value in a map for modification using a tuple of owned values as a
+                /// key by using borrowed values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut hash_map = HashMap::new();
+                /// hash_map.insert((16_i32, 32_u8, String::from("Hello, world!")), 1);
+                ///
+                /// if let Some(hits) = hash_map.get_mut_by_tuple_entries(&16, &32, &String::from("Hello, world!")) {
+                ///     *hits += 1;
+                /// }
+                /// assert_eq!(Some(&2), hash_map.get_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut tree_map = BTreeMap::new();
+                /// tree_map.insert((16_i32, 32_u8, String::from("Hello, world!")), 1);
+                ///
+                /// if let Some(hits) = tree_map.get_mut_by_tuple_entries(&16, &32, &String::from("Hello, world!")) {
+                ///     *hits += 1;
+                /// }
+                /// assert_eq!(Some(&2), tree_map.get_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// ```
+                #[allow(clippy::too_many_arguments)]
+                fn get_mut_by_tuple_entries(&mut self, $([< entry_ $ty:lower >]: &$ty,)+) -> Option<&mut Val>;
+
+                /// Remove a value from a map using a tuple of owned values as a key by using a
+                /// tuple of borrowed values, returning the value if it was in the map.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut hash_map = HashMap::new();
+                /// hash_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert_eq!(Some("first"), hash_map.remove_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// assert_eq!(None, hash_map.remove_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut tree_map = BTreeMap::new();
+                /// tree_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert_eq!(Some("first"), tree_map.remove_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// assert_eq!(None, tree_map.remove_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// ```
+                fn remove_by_tuple(&mut self, ($([< entry_ $ty:lower >],)+): ($(&$ty,)+)) -> Option<Val> {
+                    self.remove_by_tuple_entries($([< entry_ $ty:lower >],)+)
+                }
+
+                /// Remove a value from a map using a tuple of owned values as a key by using
+                /// borrowed values, returning the value if it was in the map.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut hash_map = HashMap::new();
+                /// hash_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert_eq!(Some("first"), hash_map.remove_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// assert_eq!(None, hash_map.remove_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut tree_map = BTreeMap::new();
+                /// tree_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert_eq!(Some("first"), tree_map.remove_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// assert_eq!(None, tree_map.remove_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// ```
+                #[allow(clippy::too_many_arguments)]
+                fn remove_by_tuple_entries(&mut self, $([< entry_ $ty:lower >]: &$ty,)+) -> Option<Val>;
+
+                /// Check whether a map contains a tuple of owned values as a key by using a
+                /// tuple of borrowed values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut hash_map = HashMap::new();
+                /// hash_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert!(hash_map.contains_key_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// assert!(!hash_map.contains_key_by_tuple((&8, &16, &String::from("Bye, world!"))));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut tree_map = BTreeMap::new();
+                /// tree_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert!(tree_map.contains_key_by_tuple((&16, &32, &String::from("Hello, world!"))));
+                /// assert!(!tree_map.contains_key_by_tuple((&8, &16, &String::from("Bye, world!"))));
+                /// ```
+                fn contains_key_by_tuple(&self, ($([< entry_ $ty:lower >],)+): ($(&$ty,)+)) -> bool {
+                    self.contains_key_by_tuple_entries($([< entry_ $ty:lower >],)+)
+                }
+
+                /// Check whether a map contains a tuple of owned values as a key by using
+                /// borrowed values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut hash_map = HashMap::new();
+                /// hash_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert!(hash_map.contains_key_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// assert!(!hash_map.contains_key_by_tuple_entries(&8, &16, &String::from("Bye, world!")));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTUW;
+                ///
+                /// let mut tree_map = BTreeMap::new();
+                /// tree_map.insert((16_i32, 32_u8, String::from("Hello, world!")), "first");
+                ///
+                /// assert!(tree_map.contains_key_by_tuple_entries(&16, &32, &String::from("Hello, world!")));
+                /// assert!(!tree_map.contains_key_by_tuple_entries(&8, &16, &String::from("Bye, world!")));
+                /// ```
+                #[allow(clippy::too_many_arguments)]
+                fn contains_key_by_tuple_entries(&self, $([< entry_ $ty:lower >]: &$ty,)+) -> bool;
+
+                /// Get the entry for a tuple of owned values as a key by using a tuple of
+                /// borrowed values, for in-place manipulation. Unlike with the map's own `entry`
+                /// method, the owned key is only constructed, by calling `make_key`, if a value
+                /// actually gets inserted. `make_key` must return a key equal to the borrowed
+                /// one.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashMap;
+                /// use ilyvion_util::map_extensions::MapTupleExtensionsTU;
+                ///
+                /// let mut visits: HashMap<(String, u16), u32> = HashMap::new();
+                /// for (host, port) in [("example.com", 80), ("example.com", 443), ("example.com", 80)].iter() {
+                ///     *visits
+                ///         .entry_by_tuple((*host, port), || (host.to_string(), *port))
+                ///         .or_default() += 1;
+                /// }
+                ///
+                /// assert_eq!(Some(&2), visits.get_by_tuple(("example.com", &80)));
+                /// assert_eq!(Some(&1), visits.get_by_tuple(("example.com", &443)));
+                /// ```
+                fn entry_by_tuple<F>(&mut self, ($([< entry_ $ty:lower >],)+): ($(&$ty,)+), make_key: F) -> TupleEntry<'_, Self, Val, F>
+                where
+                    Self: Sized,
+                {
+                    if self.contains_key_by_tuple_entries($([< entry_ $ty:lower >],)+) {
+                        let value = self.get_mut_by_tuple_entries($([< entry_ $ty:lower >],)+);
+                        TupleEntry::Occupied(value.expect("the key was just found in the map"))
+                    } else {
+                        TupleEntry::Vacant(VacantTupleEntry {
+                            map: self,
+                            make_key,
+                        })
+                    }
+                }
             }
-            impl<$($ty,)+ Val> [< MapTupleExtensions $($ty)+ >]<$($ty,)+ Val> for HashMap<($($ty,)+), Val>
+            impl<$([< K $ty >],)+ $($ty: ?Sized,)+ Val> [< MapTupleExtensions $($ty)+ >]<$($ty,)+ Val> for HashMap<($([< K $ty >],)+), Val>
             where
-                ($($ty,)+): Eq + Hash,
+                ($([< K $ty >],)+): Eq + Hash,
+                $([< K $ty >]: Borrow<$ty>,)+
                 $($ty: Eq + Hash,)+
             {
                 #[allow(nonstandard_style)]
@@ -203,10 +472,32 @@ macro_rules! tuple_borrowing {
                     let k: &dyn [< HashTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
                     self.get(k)
                 }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn get_mut_by_tuple_entries(&mut self, $([< entry $ty >]: &$ty,)+) -> Option<&mut Val> {
+                    let k: &dyn [< HashTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.get_mut(k)
+                }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn remove_by_tuple_entries(&mut self, $([< entry $ty >]: &$ty,)+) -> Option<Val> {
+                    let k: &dyn [< HashTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.remove(k)
+                }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn contains_key_by_tuple_entries(&self, $([< entry $ty >]: &$ty,)+) -> bool {
+                    let k: &dyn [< HashTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.contains_key(k)
+                }
             }
-            impl<$($ty,)+ Val> [< MapTupleExtensions $($ty)+ >]<$($ty,)+ Val> for BTreeMap<($($ty,)+), Val>
+            impl<$([< K $ty >],)+ $($ty: ?Sized,)+ Val> [< MapTupleExtensions $($ty)+ >]<$($ty,)+ Val> for BTreeMap<($([< K $ty >],)+), Val>
             where
-                ($($ty,)+): Ord,
+                ($([< K $ty >],)+): Ord,
+                $([< K $ty >]: Borrow<$ty>,)+
                 $($ty: Ord,)+
             {
                 #[allow(nonstandard_style)]
@@ -215,6 +506,212 @@ macro_rules! tuple_borrowing {
                     let k: &dyn [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
                     self.get(k)
                 }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn get_mut_by_tuple_entries(&mut self, $([< entry $ty >]: &$ty,)+) -> Option<&mut Val> {
+                    let k: &dyn [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.get_mut(k)
+                }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn remove_by_tuple_entries(&mut self, $([< entry $ty >]: &$ty,)+) -> Option<Val> {
+                    let k: &dyn [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.remove(k)
+                }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn contains_key_by_tuple_entries(&self, $([< entry $ty >]: &$ty,)+) -> bool {
+                    let k: &dyn [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.contains_key(k)
+                }
+            }
+
+            /// The methods provided by this extension trait allow you to look up values in a set
+            /// of tuples — i.e.
+            #[doc = ::std::concat!("`", ::std::stringify!(($([< K $ty >]),+)), "`")]
+            /// — using a tuple of borrowed values, using the same trick as
+            #[doc = ::std::concat!("[`MapTupleExtensions", $(::std::stringify!($ty),)+ "`].")]
+            pub trait [< SetTupleExtensions $($ty)+ >]<$($ty: ?Sized,)+ Key> {
+                /// Check whether a set contains a tuple of owned values by using a tuple of
+                /// borrowed values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut hash_set = HashSet::new();
+                /// hash_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// assert!(hash_set.contains_by_tuple((&16, &32, "Hello, world!")));
+                /// assert!(!hash_set.contains_by_tuple((&8, &16, "Bye, world!")));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut tree_set = BTreeSet::new();
+                /// tree_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// assert!(tree_set.contains_by_tuple((&16, &32, "Hello, world!")));
+                /// assert!(!tree_set.contains_by_tuple((&8, &16, "Bye, world!")));
+                /// ```
+                fn contains_by_tuple(&self, ($([< entry_ $ty:lower >],)+): ($(&$ty,)+)) -> bool {
+                    self.contains_by_tuple_entries($([< entry_ $ty:lower >],)+)
+                }
+
+                /// Check whether a set contains a tuple of owned values by using borrowed
+                /// values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut hash_set = HashSet::new();
+                /// hash_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// assert!(hash_set.contains_by_tuple_entries(&16, &32, "Hello, world!"));
+                /// assert!(!hash_set.contains_by_tuple_entries(&8, &16, "Bye, world!"));
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut tree_set = BTreeSet::new();
+                /// tree_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// assert!(tree_set.contains_by_tuple_entries(&16, &32, "Hello, world!"));
+                /// assert!(!tree_set.contains_by_tuple_entries(&8, &16, "Bye, world!"));
+                /// ```
+                #[allow(clippy::too_many_arguments)]
+                fn contains_by_tuple_entries(&self, $([< entry_ $ty:lower >]: &$ty,)+) -> bool;
+
+                /// Remove and return a tuple of owned values from a set by using a tuple of
+                /// borrowed values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut hash_set = HashSet::new();
+                /// hash_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// let taken = hash_set.take_by_tuple((&16, &32, "Hello, world!"));
+                /// assert_eq!(Some((16, 32, String::from("Hello, world!"))), taken);
+                /// assert!(hash_set.is_empty());
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut tree_set = BTreeSet::new();
+                /// tree_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// let taken = tree_set.take_by_tuple((&16, &32, "Hello, world!"));
+                /// assert_eq!(Some((16, 32, String::from("Hello, world!"))), taken);
+                /// assert!(tree_set.is_empty());
+                /// ```
+                fn take_by_tuple(&mut self, ($([< entry_ $ty:lower >],)+): ($(&$ty,)+)) -> Option<Key> {
+                    self.take_by_tuple_entries($([< entry_ $ty:lower >],)+)
+                }
+
+                /// Remove and return a tuple of owned values from a set by using borrowed
+                /// values.
+                ///
+                /// # Examples
+                ///
+                /// Because the trait implementations are macro generated, the example will only
+                /// use a specific tuple size, but it works the same for all tuples up to arity
+                /// 10.
+                ///
+                /// ```
+                /// use std::collections::HashSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut hash_set = HashSet::new();
+                /// hash_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// let taken = hash_set.take_by_tuple_entries(&16, &32, "Hello, world!");
+                /// assert_eq!(Some((16, 32, String::from("Hello, world!"))), taken);
+                /// assert!(hash_set.is_empty());
+                /// ```
+                ///
+                /// ```
+                /// use std::collections::BTreeSet;
+                /// use ilyvion_util::map_extensions::SetTupleExtensionsTUW;
+                ///
+                /// let mut tree_set = BTreeSet::new();
+                /// tree_set.insert((16_i32, 32_u8, String::from("Hello, world!")));
+                ///
+                /// let taken = tree_set.take_by_tuple_entries(&16, &32, "Hello, world!");
+                /// assert_eq!(Some((16, 32, String::from("Hello, world!"))), taken);
+                /// assert!(tree_set.is_empty());
+                /// ```
+                #[allow(clippy::too_many_arguments)]
+                fn take_by_tuple_entries(&mut self, $([< entry_ $ty:lower >]: &$ty,)+) -> Option<Key>;
+            }
+            impl<$([< K $ty >],)+ $($ty: ?Sized,)+> [< SetTupleExtensions $($ty)+ >]<$($ty,)+ ($([< K $ty >],)+)> for HashSet<($([< K $ty >],)+)>
+            where
+                ($([< K $ty >],)+): Eq + Hash,
+                $([< K $ty >]: Borrow<$ty>,)+
+                $($ty: Eq + Hash,)+
+            {
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn contains_by_tuple_entries(&self, $([< entry $ty >]: &$ty,)+) -> bool {
+                    let k: &dyn [< HashTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.contains(k)
+                }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn take_by_tuple_entries(&mut self, $([< entry $ty >]: &$ty,)+) -> Option<($([< K $ty >],)+)> {
+                    let k: &dyn [< HashTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.take(k)
+                }
+            }
+            impl<$([< K $ty >],)+ $($ty: ?Sized,)+> [< SetTupleExtensions $($ty)+ >]<$($ty,)+ ($([< K $ty >],)+)> for BTreeSet<($([< K $ty >],)+)>
+            where
+                ($([< K $ty >],)+): Ord,
+                $([< K $ty >]: Borrow<$ty>,)+
+                $($ty: Ord,)+
+            {
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn contains_by_tuple_entries(&self, $([< entry $ty >]: &$ty,)+) -> bool {
+                    let k: &dyn [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.contains(k)
+                }
+
+                #[allow(nonstandard_style)]
+                #[inline]
+                fn take_by_tuple_entries(&mut self, $([< entry $ty >]: &$ty,)+) -> Option<($([< K $ty >],)+)> {
+                    let k: &dyn [< OrdTupleBorrow $($ty)+ >]<$($ty,)+> = &($([< entry $ty >],)+);
+                    self.take(k)
+                }
             }
         }
     };
@@ -237,3 +734,655 @@ tuple_borrowing!(T U W X Y Z A B);
 tuple_borrowing!(T U W X Y Z A B C);
 
 tuple_borrowing!(T U W X Y Z A B C D);
+
+/// A view into a single entry of a map with a tuple key, which may either be vacant or occupied.
+///
+/// This `enum` is created by the `entry_by_tuple` method of the `MapTupleExtensions*` traits,
+/// e.g. [`MapTupleExtensionsTU::entry_by_tuple`].
+pub enum TupleEntry<'a, M, V, F> {
+    /// The map contains the key.
+    Occupied(&'a mut V),
+    /// The map does not contain the key.
+    Vacant(VacantTupleEntry<'a, M, F>),
+}
+
+impl<M, V: fmt::Debug, F> fmt::Debug for TupleEntry<'_, M, V, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Occupied(value) => f.debug_tuple("Occupied").field(value).finish(),
+            Self::Vacant(entry) => f.debug_tuple("Vacant").field(entry).finish(),
+        }
+    }
+}
+
+/// A view into a vacant entry of a map with a tuple key. The owned key is only constructed when
+/// a value is inserted.
+///
+/// This `struct` is part of the [`TupleEntry`] enum.
+pub struct VacantTupleEntry<'a, M, F> {
+    map: &'a mut M,
+    make_key: F,
+}
+
+impl<M, F> fmt::Debug for VacantTupleEntry<'_, M, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VacantTupleEntry").finish_non_exhaustive()
+    }
+}
+
+macro_rules! tuple_entry_impls {
+    ($map:ident, $($bound:tt)+) => {
+        impl<'a, K: $($bound)+, V, F: FnOnce() -> K> TupleEntry<'a, $map<K, V>, V, F> {
+            /// Ensures a value is in the entry by inserting `default` if empty, and returns a
+            /// mutable reference to the value in the entry.
+            pub fn or_insert(self, default: V) -> &'a mut V {
+                self.or_insert_with(|| default)
+            }
+
+            /// Ensures a value is in the entry by inserting the result of `default` if empty,
+            /// and returns a mutable reference to the value in the entry.
+            pub fn or_insert_with<D: FnOnce() -> V>(self, default: D) -> &'a mut V {
+                match self {
+                    Self::Occupied(value) => value,
+                    Self::Vacant(entry) => entry.insert(default()),
+                }
+            }
+
+            /// Ensures a value is in the entry by inserting the default value if empty, and
+            /// returns a mutable reference to the value in the entry.
+            pub fn or_default(self) -> &'a mut V
+            where
+                V: Default,
+            {
+                self.or_insert_with(V::default)
+            }
+
+            /// Provides in-place mutable access to an occupied entry before any potential
+            /// inserts into the map.
+            #[must_use]
+            pub fn and_modify<G: FnOnce(&mut V)>(mut self, f: G) -> Self {
+                if let Self::Occupied(value) = &mut self {
+                    f(value);
+                }
+                self
+            }
+        }
+
+        impl<'a, K: $($bound)+, V, F: FnOnce() -> K> VacantTupleEntry<'a, $map<K, V>, F> {
+            /// Constructs the owned key and inserts it into the map along with `value`, returning
+            /// a mutable reference to the value.
+            pub fn insert(self, value: V) -> &'a mut V {
+                self.map.entry((self.make_key)()).or_insert(value)
+            }
+        }
+    };
+}
+
+tuple_entry_impls!(HashMap, Eq + Hash);
+
+tuple_entry_impls!(BTreeMap, Ord);
+
+/// The method provided by this extension trait allows you to merge one map into another, with a
+/// closure deciding what happens to keys that are in both.
+pub trait MapMergeExtensions<K, V> {
+    /// Moves all the entries of `other` into this map. For keys that are in both maps, `f` is
+    /// called with the key, the value in this map and the value in `other`, and its result
+    /// becomes the new value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use ilyvion_util::map_extensions::MapMergeExtensions;
+    ///
+    /// let mut config: HashMap<_, _> = [("level", 1), ("width", 80)].iter().copied().collect();
+    /// let overrides: HashMap<_, _> = [("level", 3), ("height", 24)].iter().copied().collect();
+    ///
+    /// config.merge_with(overrides, |_, base, layer| base.max(layer));
+    /// assert_eq!(config["level"], 3);
+    /// assert_eq!(config["width"], 80);
+    /// assert_eq!(config["height"], 24);
+    /// ```
+    fn merge_with<F>(&mut self, other: Self, f: F)
+    where
+        F: FnMut(&K, V, V) -> V;
+}
+
+impl<K: Eq + Hash, V> MapMergeExtensions<K, V> for HashMap<K, V> {
+    fn merge_with<F>(&mut self, other: Self, mut f: F)
+    where
+        F: FnMut(&K, V, V) -> V,
+    {
+        for (key, value) in other {
+            let (key, value) = match self.remove_entry(&key) {
+                Some((existing_key, existing)) => {
+                    let merged = f(&existing_key, existing, value);
+                    (existing_key, merged)
+                }
+                None => (key, value),
+            };
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Ord, V> MapMergeExtensions<K, V> for BTreeMap<K, V> {
+    fn merge_with<F>(&mut self, other: Self, mut f: F)
+    where
+        F: FnMut(&K, V, V) -> V,
+    {
+        for (key, value) in other {
+            let (key, value) = match self.remove_entry(&key) {
+                Some((existing_key, existing)) => {
+                    let merged = f(&existing_key, existing, value);
+                    (existing_key, merged)
+                }
+                None => (key, value),
+            };
+            self.insert(key, value);
+        }
+    }
+}
+
+/// The constructor provided by this extension trait builds a map of groups straight from an
+/// iterator of key-value pairs, collecting the values of each key into any [`Extend`] target,
+/// like a [`Vec`] or a [`HashSet`]. It complements
+/// [`IteratorExtensions::group_by_key`](crate::iterator_extensions::IteratorExtensions::group_by_key)
+/// for when the data already comes in pairs.
+pub trait FromGroupedIterator<K, V>: Sized {
+    /// Creates a map with an entry for every distinct key in `iter`, whose value contains all the
+    /// values paired with that key, in iteration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::{BTreeMap, BTreeSet, HashMap};
+    /// use ilyvion_util::map_extensions::FromGroupedIterator;
+    ///
+    /// let edges = vec![("a", 'x'), ("b", 'y'), ("a", 'z'), ("a", 'x')];
+    ///
+    /// let lists: HashMap<_, Vec<_>> = HashMap::from_grouped_iter(edges.iter().copied());
+    /// assert_eq!(lists["a"], ['x', 'z', 'x']);
+    ///
+    /// let sets: BTreeMap<_, BTreeSet<_>> = BTreeMap::from_grouped_iter(edges);
+    /// assert_eq!(sets["a"].iter().collect::<String>(), "xz");
+    /// ```
+    fn from_grouped_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>;
+}
+
+impl<K: Eq + Hash, V, C: Default + Extend<V>> FromGroupedIterator<K, V> for HashMap<K, C> {
+    fn from_grouped_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut groups = Self::new();
+        for (key, value) in iter {
+            groups
+                .entry(key)
+                .or_insert_with(C::default)
+                .extend(Some(value));
+        }
+
+        groups
+    }
+}
+
+impl<K: Ord, V, C: Default + Extend<V>> FromGroupedIterator<K, V> for BTreeMap<K, C> {
+    fn from_grouped_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut groups = Self::new();
+        for (key, value) in iter {
+            groups
+                .entry(key)
+                .or_insert_with(C::default)
+                .extend(Some(value));
+        }
+
+        groups
+    }
+}
+
+/// Where a [`PrefixProbe`] sorts relative to the keys sharing its first component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Side {
+    Before,
+    Key,
+    After,
+}
+
+trait PrefixOrd<A: ?Sized, B> {
+    fn parts(&self) -> (&A, Option<&B>, Side);
+}
+impl<KA, A: ?Sized, B> PrefixOrd<A, B> for (KA, B)
+where
+    KA: Borrow<A>,
+{
+    fn parts(&self) -> (&A, Option<&B>, Side) {
+        (self.0.borrow(), Some(&self.1), Side::Key)
+    }
+}
+impl<'r, A, B> PartialEq for dyn 'r + PrefixOrd<A, B>
+where
+    A: ?Sized + 'r + Ord,
+    B: 'r + Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<'r, A, B> Eq for dyn 'r + PrefixOrd<A, B>
+where
+    A: ?Sized + 'r + Ord,
+    B: 'r + Ord,
+{
+}
+impl<'r, A, B> PartialOrd for dyn 'r + PrefixOrd<A, B>
+where
+    A: ?Sized + 'r + Ord,
+    B: 'r + Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<'r, A, B> Ord for dyn 'r + PrefixOrd<A, B>
+where
+    A: ?Sized + 'r + Ord,
+    B: 'r + Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (first, second, side) = self.parts();
+        let (other_first, other_second, other_side) = other.parts();
+        first
+            .cmp(other_first)
+            .then_with(|| match (second, other_second) {
+                (Some(second), Some(other_second)) => second.cmp(other_second),
+                _ => side.cmp(&other_side),
+            })
+    }
+}
+impl<'r, KA, A, B> Borrow<dyn 'r + PrefixOrd<A, B>> for (KA, B)
+where
+    KA: 'r + Borrow<A>,
+    A: ?Sized + 'r + Ord,
+    B: 'r + Ord,
+{
+    fn borrow(&self) -> &(dyn 'r + PrefixOrd<A, B>) {
+        self
+    }
+}
+
+/// A stand-in key that sorts right before or right after all the keys whose first component is
+/// `first`, regardless of their second component.
+struct PrefixProbe<'a, A: ?Sized> {
+    first: &'a A,
+    side: Side,
+}
+impl<A: ?Sized, B> PrefixOrd<A, B> for PrefixProbe<'_, A> {
+    fn parts(&self) -> (&A, Option<&B>, Side) {
+        (self.first, None, self.side)
+    }
+}
+
+/// The methods provided by this extension trait allow you to query the entries of a
+/// [`BTreeMap`] with a pair key — i.e. `(KA, B)` — by the first component of the key alone,
+/// exploiting that pairs are ordered by their first component first. As with
+/// [`MapTupleExtensionsTU`], the first component can be queried using any type `KA` borrows as.
+pub trait PairKeyRangeExtensions<A: ?Sized, K, V> {
+    /// Returns an iterator over all the entries whose key has `first` as its first component,
+    /// in key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use ilyvion_util::map_extensions::PairKeyRangeExtensions;
+    ///
+    /// let mut orders = BTreeMap::new();
+    /// orders.insert((String::from("alice"), 2), "tea");
+    /// orders.insert((String::from("bob"), 1), "coffee");
+    /// orders.insert((String::from("alice"), 1), "cake");
+    ///
+    /// let alice: Vec<_> = orders.range_by_first("alice").map(|(_, order)| *order).collect();
+    /// assert_eq!(alice, ["cake", "tea"]);
+    /// ```
+    fn range_by_first(&self, first: &A) -> btree_map::Range<'_, K, V> {
+        self.range_by_first_in((Bound::Included(first), Bound::Included(first)))
+    }
+
+    /// Returns an iterator over all the entries whose key has a first component that falls within
+    /// `range`, in key order.
+    ///
+    /// # Panics
+    ///
+    /// If the range starts after it ends, or if it starts and ends at the same place and both
+    /// bounds are excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use ilyvion_util::map_extensions::PairKeyRangeExtensions;
+    ///
+    /// let readings: BTreeMap<_, _> = [((1, 'a'), 10), ((2, 'b'), 20), ((2, 'c'), 25), ((3, 'a'), 30)]
+    ///     .iter()
+    ///     .copied()
+    ///     .collect();
+    ///
+    /// let keys: Vec<_> = readings.range_by_first_in(2..).map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, [(2, 'b'), (2, 'c'), (3, 'a')]);
+    /// ```
+    fn range_by_first_in<R: RangeBounds<A>>(&self, range: R) -> btree_map::Range<'_, K, V>;
+}
+
+impl<KA, A, B, V> PairKeyRangeExtensions<A, (KA, B), V> for BTreeMap<(KA, B), V>
+where
+    KA: Ord + Borrow<A>,
+    A: ?Sized + Ord,
+    B: Ord,
+{
+    fn range_by_first_in<R: RangeBounds<A>>(&self, range: R) -> btree_map::Range<'_, (KA, B), V> {
+        let to_probe = |bound, before, after| match bound {
+            Bound::Included(first) => Some(PrefixProbe {
+                first,
+                side: before,
+            }),
+            Bound::Excluded(first) => Some(PrefixProbe { first, side: after }),
+            Bound::Unbounded => None,
+        };
+        let start = to_probe(range.start_bound(), Side::Before, Side::After);
+        let end = to_probe(range.end_bound(), Side::After, Side::Before);
+        if let (Some(start), Some(end)) = (&start, &end) {
+            let order = start.first.cmp(end.first).then(start.side.cmp(&end.side));
+            assert!(
+                order != Ordering::Greater,
+                "range start is greater than range end"
+            );
+        }
+
+        let start_bound = match &start {
+            Some(probe) => Bound::Included(probe as &dyn PrefixOrd<A, B>),
+            None => Bound::Unbounded,
+        };
+        let end_bound = match &end {
+            Some(probe) => Bound::Included(probe as &dyn PrefixOrd<A, B>),
+            None => Bound::Unbounded,
+        };
+        self.range::<dyn PrefixOrd<A, B>, _>((start_bound, end_bound))
+    }
+}
+
+// This re-export is a macro implementation detail and not part of the public API
+#[doc(hidden)]
+pub use paste as __paste;
+
+/// Generates the same kind of borrowing machinery that powers the `*_by_tuple` methods, but for a
+/// named key struct, along with a borrowed view struct that can be used to look up values in a
+/// [`HashMap`], [`BTreeMap`], [`HashSet`] or [`BTreeSet`] without having to construct (and thus
+/// clone into) an owned key.
+///
+/// The syntax of the macro is:
+/// ```rust
+/// # /*
+/// impl_struct_key_borrow! {
+///     $key_struct => $vis $view_struct { $field: $borrowed_type, ... }
+/// }
+/// # */
+/// ```
+///
+/// Given a key struct `Key`, this generates:
+/// * A view struct `$view_struct<'a>` with one `&'a $borrowed_type` field per listed field.
+/// * A trait named `KeyBorrow` (the key struct's name followed by `Borrow`), along with [`Hash`],
+///   [`Eq`] and [`Ord`] implementations for `dyn KeyBorrow`.
+/// * An implementation of `Borrow<dyn KeyBorrow>` for the key struct.
+/// * An `as_key()` method on the view struct that returns a `&dyn KeyBorrow`, ready to be passed to
+///   `get`, `contains_key`, `remove` and friends.
+///
+/// For lookups to work correctly, the fields must be listed in the same order as they are declared
+/// in the key struct, every field must be listed, each field type must implement
+/// `Borrow<$borrowed_type>`, and the key struct's [`Hash`], [`Eq`] and [`Ord`] implementations must
+/// be the derived ones (or behave identically to them.) The borrowed types must implement [`Hash`]
+/// and [`Ord`].
+///
+/// # Examples
+/// ```rust
+/// use ilyvion_util::impl_struct_key_borrow;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Key {
+///     name: String,
+///     index: u32,
+/// }
+///
+/// impl_struct_key_borrow! {
+///     Key => KeyRef { name: str, index: u32 }
+/// }
+///
+/// let mut map = HashMap::new();
+/// map.insert(Key { name: "one".to_owned(), index: 1 }, "first");
+/// map.insert(Key { name: "two".to_owned(), index: 2 }, "second");
+///
+/// assert_eq!(map.get(KeyRef { name: "two", index: &2 }.as_key()), Some(&"second"));
+/// assert_eq!(map.get(KeyRef { name: "two", index: &1 }.as_key()), None);
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(Key { name: "one".to_owned(), index: 1 }, "first");
+///
+/// assert_eq!(map.remove(KeyRef { name: "one", index: &1 }.as_key()), Some("first"));
+/// assert!(map.is_empty());
+/// ```
+#[macro_export]
+macro_rules! impl_struct_key_borrow {
+    (
+        $key:ident => $vis:vis $view:ident { $($field:ident: $ty:ty),+ $(,)? }
+    ) => {
+        $crate::map_extensions::__paste::paste! {
+            #[doc = "Borrowed view of a [`" $key "`], used to look it up without cloning."]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $vis struct $view<'a> {
+                $(
+                    #[doc = "Borrowed `" $field "` field."]
+                    pub $field: &'a $ty,
+                )+
+            }
+
+            impl<'a> $view<'a> {
+                #[doc = "Returns this view as a `&dyn " $key "Borrow`, which any [`" $key "`] "]
+                #[doc = "can be borrowed as."]
+                #[must_use]
+                pub fn as_key(&self) -> &(dyn [< $key Borrow >] + 'a) {
+                    self
+                }
+            }
+
+            #[doc = "Implementation detail of [`" $view "`]; something that can be borrowed "]
+            #[doc = "as the fields of a [`" $key "`]."]
+            $vis trait [< $key Borrow >] {
+                #[doc = "Returns the borrowed fields of the key, in declaration order."]
+                fn key_fields(&self) -> ($(&$ty,)+);
+            }
+
+            impl [< $key Borrow >] for $key {
+                fn key_fields(&self) -> ($(&$ty,)+) {
+                    ($(::std::borrow::Borrow::<$ty>::borrow(&self.$field),)+)
+                }
+            }
+
+            impl [< $key Borrow >] for $view<'_> {
+                fn key_fields(&self) -> ($(&$ty,)+) {
+                    ($(self.$field,)+)
+                }
+            }
+
+            impl<'a> ::std::borrow::Borrow<dyn [< $key Borrow >] + 'a> for $key {
+                fn borrow(&self) -> &(dyn [< $key Borrow >] + 'a) {
+                    self
+                }
+            }
+
+            impl ::std::hash::Hash for dyn [< $key Borrow >] + '_ {
+                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                    self.key_fields().hash(state);
+                }
+            }
+
+            impl ::std::cmp::PartialEq for dyn [< $key Borrow >] + '_ {
+                fn eq(&self, other: &Self) -> bool {
+                    self.key_fields() == other.key_fields()
+                }
+            }
+
+            impl ::std::cmp::Eq for dyn [< $key Borrow >] + '_ {}
+
+            impl ::std::cmp::PartialOrd for dyn [< $key Borrow >] + '_ {
+                fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl ::std::cmp::Ord for dyn [< $key Borrow >] + '_ {
+                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    self.key_fields().cmp(&other.key_fields())
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_by_first_honors_every_kind_of_bound() {
+        let map: BTreeMap<_, _> = (0..4)
+            .flat_map(|first| (0..2).map(move |second| ((first, second), ())))
+            .collect();
+        let firsts = |range: (Bound<i32>, Bound<i32>)| -> Vec<i32> {
+            map.range_by_first_in(range)
+                .map(|((first, _), _)| *first)
+                .collect()
+        };
+
+        assert_eq!(firsts((Bound::Excluded(1), Bound::Excluded(3))), [2, 2]);
+        assert_eq!(firsts((Bound::Included(1), Bound::Excluded(1))), [0; 0]);
+        assert_eq!(firsts((Bound::Unbounded, Bound::Included(0))), [0, 0]);
+        assert_eq!(map.range_by_first(&7).count(), 0);
+    }
+
+    #[test]
+    fn entry_by_tuple_only_makes_keys_when_inserting() {
+        let mut map: BTreeMap<(String, String), i32> = BTreeMap::new();
+        let mut keys_made = 0;
+        for _ in 0..3 {
+            let entry = map.entry_by_tuple(("a", "b"), || {
+                keys_made += 1;
+                (String::from("a"), String::from("b"))
+            });
+            *entry.and_modify(|v| *v *= 10).or_insert(1) += 1;
+        }
+
+        assert_eq!(keys_made, 1);
+        assert_eq!(map.get_by_tuple(("a", "b")), Some(&211));
+    }
+
+    #[test]
+    fn merge_with_only_resolves_shared_keys() {
+        let mut base: BTreeMap<_, _> = vec![(1, "a"), (2, "b")].into_iter().collect();
+        let other: BTreeMap<_, _> = vec![(2, "c"), (3, "d")].into_iter().collect();
+        let mut conflicts = vec![];
+        base.merge_with(other, |key, existing, new| {
+            conflicts.push(*key);
+            if existing < new {
+                new
+            } else {
+                existing
+            }
+        });
+
+        assert_eq!(conflicts, [2]);
+        assert_eq!(
+            base.into_iter().collect::<Vec<_>>(),
+            [(1, "a"), (2, "c"), (3, "d")]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range start is greater than range end")]
+    fn range_by_first_rejects_backwards_ranges() {
+        let map: BTreeMap<(i32, i32), ()> = BTreeMap::new();
+        let _ = map.range_by_first_in((Bound::Excluded(1), Bound::Excluded(1)));
+    }
+
+    #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    struct StructKey {
+        name: String,
+        tags: Vec<u8>,
+        index: u32,
+    }
+
+    impl_struct_key_borrow! {
+        StructKey => StructKeyRef { name: str, tags: [u8], index: u32 }
+    }
+
+    #[test]
+    fn struct_key_borrow_looks_up_without_cloning() {
+        let key = |name: &str, tags: &[u8], index| StructKey {
+            name: name.to_owned(),
+            tags: tags.to_vec(),
+            index,
+        };
+
+        let hash_set: HashSet<_> = vec![key("a", &[1], 1), key("a", &[1, 2], 1), key("b", &[], 0)]
+            .into_iter()
+            .collect();
+        assert!(hash_set.contains(
+            StructKeyRef {
+                name: "a",
+                tags: &[1, 2],
+                index: &1
+            }
+            .as_key()
+        ));
+        assert!(!hash_set.contains(
+            StructKeyRef {
+                name: "a",
+                tags: &[2],
+                index: &1
+            }
+            .as_key()
+        ));
+
+        let btree_map: BTreeMap<_, _> = vec![
+            (key("b", &[], 0), 'b'),
+            (key("a", &[1, 2], 1), 'y'),
+            (key("a", &[1], 1), 'x'),
+        ]
+        .into_iter()
+        .collect();
+        for (existing, value) in &btree_map {
+            let view = StructKeyRef {
+                name: &existing.name,
+                tags: &existing.tags,
+                index: &existing.index,
+            };
+            assert_eq!(btree_map.get(view.as_key()), Some(value));
+        }
+        assert_eq!(
+            btree_map.get(
+                StructKeyRef {
+                    name: "a",
+                    tags: &[],
+                    index: &1
+                }
+                .as_key()
+            ),
+            None
+        );
+    }
+}
@@ -1,8 +1,114 @@
 //! Provides functionality for treating a one-dimensional [`Vec`]/[`slice`] as if it were
-//! two-dimensional.
+//! two- or three-dimensional.
 
+mod display;
+mod grid;
+mod iter;
+mod neighbors;
 mod slice2d;
+mod slice3d;
 mod vec2d;
+mod vec3d;
 
+pub use display::DisplayGrid;
+pub use grid::*;
+pub use iter::*;
+pub use neighbors::{Border, Neighborhood, Neighbors};
 pub use slice2d::*;
+pub use slice3d::*;
 pub use vec2d::*;
+pub use vec3d::*;
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Bound, Range, RangeBounds};
+
+/// The error type returned when data doesn't fit the requested shape of a multi-dimensional type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    /// A row didn't have the same length as the rows before it.
+    RaggedRow {
+        /// The index of the offending row
+        row: usize,
+        /// The length of the rows before the offending row
+        expected: usize,
+        /// The length of the offending row
+        found: usize,
+    },
+    /// The length of the data doesn't divide evenly into rows of the requested number of columns.
+    UnevenLength {
+        /// The length of the data
+        len: usize,
+        /// The requested number of columns
+        columns: usize,
+    },
+    /// The requested number of columns was zero, which leaves the number of rows undefined.
+    ZeroColumns,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has length {}, but the rows before it have length {}",
+                row, found, expected
+            ),
+            Self::UnevenLength { len, columns } => write!(
+                f,
+                "a length of {} does not divide evenly into rows of {} columns",
+                len, columns
+            ),
+            Self::ZeroColumns => f.write_str("the number of columns must not be zero"),
+        }
+    }
+}
+
+impl Error for ShapeError {}
+
+/// Returns the number of rows of `columns` entries each that `len` entries make up.
+fn rows_of(len: usize, columns: usize) -> Result<usize, ShapeError> {
+    if columns == 0 {
+        Err(ShapeError::ZeroColumns)
+    } else if !len.is_multiple_of(columns) {
+        Err(ShapeError::UnevenLength { len, columns })
+    } else {
+        Ok(len / columns)
+    }
+}
+
+/// Converts `range` into a concrete [`Range`] within `0..len`.
+///
+/// # Panics
+///
+/// If `range` starts after it ends, or extends beyond `len`.
+fn bounded_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "range starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end {} out of bounds for length {}",
+        end,
+        len
+    );
+
+    start..end
+}
@@ -0,0 +1,134 @@
+use super::iter::EnumerateCells;
+use std::fmt::{self, Alignment, Display};
+
+/// Helper struct for displaying a two-dimensional type one row per line, with every cell padded
+/// to the width of the widest cell in its column.
+///
+/// This `struct` is created by the `display_with` method on [`Vec2D`](super::Vec2D) and
+/// [`Window2D`](super::Window2D).
+pub struct DisplayGrid<'a, T, F> {
+    raw: &'a [T],
+    rows: usize,
+    columns: usize,
+    stride: usize,
+    func: F,
+    separator: &'a str,
+    alignment: Alignment,
+}
+
+impl<'a, T, F, D> DisplayGrid<'a, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    pub(super) fn new(
+        raw: &'a [T],
+        (rows, columns, stride): (usize, usize, usize),
+        func: F,
+    ) -> Self {
+        Self {
+            raw,
+            rows,
+            columns,
+            stride,
+            func,
+            separator: " ",
+            alignment: Alignment::Right,
+        }
+    }
+
+    /// Sets the string placed between the cells of a row. Defaults to a single space.
+    #[must_use]
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets how cells narrower than their column are aligned. Defaults to
+    /// [`Alignment::Right`].
+    #[must_use]
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<T, F, D> Display for DisplayGrid<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<_> = EnumerateCells::new(self.raw, self.rows, self.columns, self.stride)
+            .map(|(_, _, entry)| (self.func)(entry).to_string())
+            .collect();
+        let mut widths = vec![0; self.columns];
+        for (i, cell) in cells.iter().enumerate() {
+            let width = &mut widths[i % self.columns];
+            *width = (*width).max(cell.chars().count());
+        }
+
+        for (y, row) in cells.chunks(self.columns.max(1)).enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for (x, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                if x > 0 {
+                    f.write_str(self.separator)?;
+                }
+                // Avoid trailing whitespace at the end of each line
+                let width = if x + 1 == self.columns && self.alignment == Alignment::Left {
+                    0
+                } else {
+                    width
+                };
+                match self.alignment {
+                    Alignment::Left => write!(f, "{:<width$}", cell, width = width)?,
+                    Alignment::Right => write!(f, "{:>width$}", cell, width = width)?,
+                    Alignment::Center => write!(f, "{:^width$}", cell, width = width)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, F> fmt::Debug for DisplayGrid<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayGrid")
+            .field("rows", &self.rows)
+            .field("columns", &self.columns)
+            .field("stride", &self.stride)
+            .field("separator", &self.separator)
+            .field("alignment", &self.alignment)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_columns_independently() {
+        let values = [1, 200, 3, 40, 5, 6];
+        let grid = DisplayGrid::new(&values, (2, 3, 3), |n: &i32| *n);
+
+        assert_eq!(grid.to_string(), " 1 200 3\n40   5 6");
+
+        let grid = grid.separator(" | ").align(Alignment::Left);
+        assert_eq!(grid.to_string(), "1  | 200 | 3\n40 | 5   | 6");
+
+        let grid = grid.separator("").align(Alignment::Center);
+        assert_eq!(grid.to_string(), "1 2003\n40 5 6");
+    }
+
+    #[test]
+    fn empty_grid_displays_nothing() {
+        let values: [u8; 0] = [];
+
+        assert_eq!(DisplayGrid::new(&values, (0, 3, 3), |n| *n).to_string(), "");
+        assert_eq!(DisplayGrid::new(&values, (3, 0, 0), |n| *n).to_string(), "");
+    }
+}
@@ -0,0 +1,167 @@
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::slice;
+
+/// This struct represents a two-dimensional view of a one-dimensional slice whose number of
+/// columns is known at compile time. Each row is a `[T; COLS]`, so iterating over rows yields
+/// fixed-size arrays and indexing into a row with a constant column needs no bounds check.
+///
+/// This is a zero-cost alternative to [`Window2D`](super::Window2D) for when the number of
+/// columns doesn't need to be decided at run-time.
+///
+/// # Example
+/// ```
+/// # use ilyvion_util::multi_dimensional::Grid;
+/// let mut values = [0u32; 8];
+/// let mut grid = Grid::<_, 2>::new_mut(&mut values);
+/// for (y, row) in grid.rows_mut().enumerate() {
+///     row[1] = y as u32 + 1;
+/// }
+///
+/// assert_eq!(values, [0, 1, 0, 2, 0, 3, 0, 4]);
+/// ```
+#[derive(Debug)]
+pub struct Grid<T, const COLS: usize> {
+    raw: T,
+}
+
+impl<'b, T, const COLS: usize> Grid<&'b mut [[T; COLS]], COLS> {
+    /// Creates a new `Grid` with rows of `COLS` entries each. (I.e., `g[rows][COLS]`)
+    ///
+    /// # Panics
+    ///
+    /// If `COLS` is zero or the length of `raw` cannot be divided evenly into `COLS`.
+    pub fn new_mut(raw: &'b mut [T]) -> Self {
+        let (rows, remainder) = raw.as_chunks_mut();
+        if !remainder.is_empty() {
+            panic!("The length of raw must divide evenly into COLS.");
+        }
+
+        Self { raw: rows }
+    }
+}
+
+impl<'b, T, const COLS: usize> Grid<&'b [[T; COLS]], COLS> {
+    /// Creates a new `Grid` with rows of `COLS` entries each. (I.e., `g[rows][COLS]`)
+    ///
+    /// # Panics
+    ///
+    /// If `COLS` is zero or the length of `raw` cannot be divided evenly into `COLS`.
+    #[must_use]
+    pub fn new_ref(raw: &'b [T]) -> Self {
+        let (rows, remainder) = raw.as_chunks();
+        if !remainder.is_empty() {
+            panic!("The length of raw must divide evenly into COLS.");
+        }
+
+        Self { raw: rows }
+    }
+}
+
+impl<T, R: Deref<Target = [[T; COLS]]>, const COLS: usize> Grid<R, COLS> {
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn row_count(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Returns a reference to the entry at `row`, `column`, or [`None`] if either is out of bounds.
+    #[must_use]
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.raw.get(row).and_then(|r| r.get(column))
+    }
+
+    /// Returns an iterator over the rows of this `Grid`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Grid;
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let grid = Grid::<_, 3>::new_ref(&values);
+    ///
+    /// let sums: Vec<i32> = grid.rows().map(|[a, b, c]| a + b + c).collect();
+    /// assert_eq!(sums, [6, 15]);
+    /// ```
+    pub fn rows(&self) -> slice::Iter<'_, [T; COLS]> {
+        self.raw.iter()
+    }
+
+    /// Returns the rows of this `Grid` as a slice of arrays.
+    #[must_use]
+    pub fn as_rows(&self) -> &[[T; COLS]] {
+        &self.raw
+    }
+}
+
+impl<T, R: DerefMut<Target = [[T; COLS]]>, const COLS: usize> Grid<R, COLS> {
+    /// Returns a mutable reference to the entry at `row`, `column`, or [`None`] if either is out
+    /// of bounds.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.raw.get_mut(row).and_then(|r| r.get_mut(column))
+    }
+
+    /// Returns an iterator over mutable references to the rows of this `Grid`.
+    pub fn rows_mut(&mut self) -> slice::IterMut<'_, [T; COLS]> {
+        self.raw.iter_mut()
+    }
+
+    /// Returns the rows of this `Grid` as a mutable slice of arrays.
+    pub fn as_rows_mut(&mut self) -> &mut [[T; COLS]] {
+        &mut self.raw
+    }
+}
+
+impl<T, R: Deref<Target = [[T; COLS]]>, const COLS: usize> Index<usize> for Grid<R, COLS> {
+    type Output = [T; COLS];
+
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.raw[row]
+    }
+}
+
+impl<T, R: DerefMut<Target = [[T; COLS]]>, const COLS: usize> IndexMut<usize> for Grid<R, COLS> {
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        &mut self.raw[row]
+    }
+}
+
+impl<T, R: Deref<Target = [[T; COLS]]>, const COLS: usize> Index<(usize, usize)> for Grid<R, COLS> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.raw[index.0][index.1]
+    }
+}
+
+impl<T, R: DerefMut<Target = [[T; COLS]]>, const COLS: usize> IndexMut<(usize, usize)>
+    for Grid<R, COLS>
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.raw[index.0][index.1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexing_works_correctly() {
+        let mut values = [0; 6];
+        let mut grid = Grid::<_, 3>::new_mut(&mut values);
+        grid[0][2] = 1;
+        grid[(1, 0)] = 2;
+        *grid.get_mut(1, 2).unwrap() = 3;
+
+        assert_eq!(grid.row_count(), 2);
+        assert_eq!(grid.get(1, 3), None);
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.as_rows(), [[0, 0, 1], [2, 0, 3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide evenly")]
+    fn uneven_length_panics() {
+        let values = [0; 5];
+        let _ = Grid::<_, 2>::new_ref(&values);
+    }
+}
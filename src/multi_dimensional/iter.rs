@@ -0,0 +1,315 @@
+use std::iter::{Enumerate, FusedIterator};
+use std::{slice, vec};
+
+/// Returns the number of entries spanned by `rows` rows of `columns` entries each, when
+/// consecutive rows start `stride` entries apart.
+fn covered_len(rows: usize, columns: usize, stride: usize) -> usize {
+    if rows == 0 || columns == 0 {
+        0
+    } else {
+        (rows - 1) * stride + columns
+    }
+}
+
+macro_rules! enumerate_cells_iterator_impls {
+    ($name:ident, $($mutability:ident)?) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = (usize, usize, &'a $($mutability)? T);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    if let Some((y, row)) = &mut self.front {
+                        if let Some((x, entry)) = row.next() {
+                            return Some((*y, x, entry));
+                        }
+                        self.front = None;
+                    }
+
+                    match self.rows.next() {
+                        Some((y, chunk)) => {
+                            self.front = Some((y, (&$($mutability)? chunk[..self.columns]).into_iter().enumerate()));
+                        }
+                        None => {
+                            let (y, row) = self.back.as_mut()?;
+                            return row.next().map(|(x, entry)| (*y, x, entry));
+                        }
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let front = self.front.as_ref().map_or(0, |(_, row)| row.len());
+                let back = self.back.as_ref().map_or(0, |(_, row)| row.len());
+                let len = front + back + self.rows.len() * self.columns;
+                (len, Some(len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $name<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                loop {
+                    if let Some((y, row)) = &mut self.back {
+                        if let Some((x, entry)) = row.next_back() {
+                            return Some((*y, x, entry));
+                        }
+                        self.back = None;
+                    }
+
+                    match self.rows.next_back() {
+                        Some((y, chunk)) => {
+                            self.back = Some((y, (&$($mutability)? chunk[..self.columns]).into_iter().enumerate()));
+                        }
+                        None => {
+                            let (y, row) = self.front.as_mut()?;
+                            return row.next_back().map(|(x, entry)| (*y, x, entry));
+                        }
+                    }
+                }
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+/// An iterator over the entries of a two-dimensional type in reading order, yielding the row and
+/// column of each entry along with a reference to it.
+///
+/// This `struct` is created by the `enumerate_cells` method on [`Vec2D`](super::Vec2D) and
+/// [`Window2D`](super::Window2D).
+#[derive(Debug, Clone)]
+pub struct EnumerateCells<'a, T> {
+    rows: Enumerate<slice::Chunks<'a, T>>,
+    columns: usize,
+    front: Option<(usize, Enumerate<slice::Iter<'a, T>>)>,
+    back: Option<(usize, Enumerate<slice::Iter<'a, T>>)>,
+}
+
+impl<'a, T> EnumerateCells<'a, T> {
+    pub(super) fn new(raw: &'a [T], rows: usize, columns: usize, stride: usize) -> Self {
+        Self {
+            rows: raw[..covered_len(rows, columns, stride)]
+                .chunks(stride.max(1))
+                .enumerate(),
+            columns,
+            front: None,
+            back: None,
+        }
+    }
+}
+
+enumerate_cells_iterator_impls!(EnumerateCells,);
+
+/// An iterator over the entries of a two-dimensional type in reading order, yielding the row and
+/// column of each entry along with a mutable reference to it.
+///
+/// This `struct` is created by the `enumerate_cells_mut` method on [`Vec2D`](super::Vec2D) and
+/// [`Window2D`](super::Window2D).
+#[derive(Debug)]
+pub struct EnumerateCellsMut<'a, T> {
+    rows: Enumerate<slice::ChunksMut<'a, T>>,
+    columns: usize,
+    front: Option<(usize, Enumerate<slice::IterMut<'a, T>>)>,
+    back: Option<(usize, Enumerate<slice::IterMut<'a, T>>)>,
+}
+
+impl<'a, T> EnumerateCellsMut<'a, T> {
+    pub(super) fn new(raw: &'a mut [T], rows: usize, columns: usize, stride: usize) -> Self {
+        Self {
+            rows: raw[..covered_len(rows, columns, stride)]
+                .chunks_mut(stride.max(1))
+                .enumerate(),
+            columns,
+            front: None,
+            back: None,
+        }
+    }
+}
+
+enumerate_cells_iterator_impls!(EnumerateCellsMut, mut);
+
+macro_rules! entries_iterator_impls {
+    ($name:ident, $($mutability:ident)?) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = &'a $($mutability)? T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.cells.next().map(|(_, _, entry)| entry)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.cells.size_hint()
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $name<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.cells.next_back().map(|(_, _, entry)| entry)
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+/// An iterator over references to the entries of a [`Window2D`](super::Window2D) in reading
+/// order.
+///
+/// This `struct` is created by the `iter` method on [`Window2D`](super::Window2D).
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    cells: EnumerateCells<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(super) fn new(raw: &'a [T], rows: usize, columns: usize, stride: usize) -> Self {
+        Self {
+            cells: EnumerateCells::new(raw, rows, columns, stride),
+        }
+    }
+}
+
+entries_iterator_impls!(Iter,);
+
+/// An iterator over mutable references to the entries of a [`Window2D`](super::Window2D) in
+/// reading order.
+///
+/// This `struct` is created by the `iter_mut` method on [`Window2D`](super::Window2D).
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    cells: EnumerateCellsMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(super) fn new(raw: &'a mut [T], rows: usize, columns: usize, stride: usize) -> Self {
+        Self {
+            cells: EnumerateCellsMut::new(raw, rows, columns, stride),
+        }
+    }
+}
+
+entries_iterator_impls!(IterMut, mut);
+
+/// An iterator that moves the rows out of a [`Vec2D`](super::Vec2D), yielding each one as a
+/// [`Vec`].
+///
+/// This `struct` is created by the `into_rows` method on [`Vec2D`](super::Vec2D).
+#[derive(Debug, Clone)]
+pub struct IntoRows<T> {
+    raw: vec::IntoIter<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> IntoRows<T> {
+    pub(super) fn new(raw: Vec<T>, rows: usize, columns: usize) -> Self {
+        Self {
+            raw: raw.into_iter(),
+            rows,
+            columns,
+        }
+    }
+}
+
+impl<T> Iterator for IntoRows<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+        self.rows -= 1;
+
+        Some(self.raw.by_ref().take(self.columns).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoRows<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None;
+        }
+        self.rows -= 1;
+
+        let mut row: Vec<_> = self.raw.by_ref().rev().take(self.columns).collect();
+        row.reverse();
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoRows<T> {}
+
+impl<T> FusedIterator for IntoRows<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_entries_outside_the_columns() {
+        let values: Vec<_> = (0..11).collect();
+
+        let cells: Vec<_> = EnumerateCells::new(&values, 3, 2, 4).collect();
+        assert_eq!(
+            cells,
+            [
+                (0, 0, &0),
+                (0, 1, &1),
+                (1, 0, &4),
+                (1, 1, &5),
+                (2, 0, &8),
+                (2, 1, &9)
+            ]
+        );
+
+        let mut iter = EnumerateCells::new(&values, 3, 2, 4);
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next_back(), Some((2, 1, &9)));
+        assert_eq!(iter.next(), Some((0, 0, &0)));
+        assert_eq!(iter.len(), 4);
+        let middle: Vec<_> = iter.rev().collect();
+        assert_eq!(middle, [(2, 0, &8), (1, 1, &5), (1, 0, &4), (0, 1, &1)]);
+    }
+
+    #[test]
+    fn mutates_entries_inside_the_columns() {
+        let mut values = [0; 7];
+
+        for (y, x, entry) in EnumerateCellsMut::new(&mut values, 2, 3, 4) {
+            *entry = 10 * (y + 1) + x;
+        }
+        assert_eq!(values, [10, 11, 12, 0, 20, 21, 22]);
+    }
+
+    #[test]
+    fn entry_iterators_follow_the_stride() {
+        let mut values: Vec<_> = (0..7).collect();
+
+        let entries: Vec<_> = Iter::new(&values, 2, 3, 4).rev().copied().collect();
+        assert_eq!(entries, [6, 5, 4, 2, 1, 0]);
+
+        IterMut::new(&mut values, 2, 3, 4).for_each(|entry| *entry *= 10);
+        assert_eq!(values, [0, 10, 20, 3, 40, 50, 60]);
+    }
+
+    #[test]
+    fn into_rows_from_both_ends() {
+        let mut rows = IntoRows::new((0..6).collect(), 3, 2);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows.next_back(), Some(vec![4, 5]));
+        assert_eq!(rows.next(), Some(vec![0, 1]));
+        assert_eq!(rows.next_back(), Some(vec![2, 3]));
+        assert_eq!(rows.next(), None);
+
+        let empty_rows: Vec<Vec<u8>> = IntoRows::new(vec![], 2, 0).collect();
+        assert_eq!(empty_rows, [Vec::<u8>::new(), Vec::new()]);
+    }
+}
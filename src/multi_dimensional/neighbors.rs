@@ -0,0 +1,225 @@
+use std::iter::FusedIterator;
+
+/// The offsets of the von Neumann neighborhood, in reading order.
+pub(super) const NEIGHBORS_4: &[(isize, isize)] = &[(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The offsets of the Moore neighborhood, in reading order.
+pub(super) const NEIGHBORS_8: &[(isize, isize)] = &[
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// An iterator over the in-bounds neighbors of an entry in a two-dimensional type, yielding the
+/// `(row, column)` of each neighbor along with a reference to it.
+///
+/// This `struct` is created by the `neighbors4` and `neighbors8` methods on
+/// [`Vec2D`](super::Vec2D) and [`Window2D`](super::Window2D).
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, T> {
+    raw: &'a [T],
+    rows: usize,
+    columns: usize,
+    stride: usize,
+    row: usize,
+    column: usize,
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+}
+
+impl<'a, T> Neighbors<'a, T> {
+    pub(super) fn new(
+        raw: &'a [T],
+        (rows, columns, stride): (usize, usize, usize),
+        (row, column): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> Self {
+        Self {
+            raw,
+            rows,
+            columns,
+            stride,
+            row,
+            column,
+            offsets: offsets.iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &(dy, dx) in &mut self.offsets {
+            let row = match self.row.checked_add_signed(dy) {
+                Some(row) if row < self.rows => row,
+                _ => continue,
+            };
+            let column = match self.column.checked_add_signed(dx) {
+                Some(column) if column < self.columns => column,
+                _ => continue,
+            };
+
+            if let Some(entry) = self.raw.get(row * self.stride + column) {
+                return Some(((row, column), entry));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.offsets.size_hint().1)
+    }
+}
+
+impl<T> FusedIterator for Neighbors<'_, T> {}
+
+/// Decides what a [`Neighborhood`] sees where it extends beyond the edges of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Border {
+    /// Positions outside the grid are missing from the neighborhood.
+    Ignore,
+    /// Positions outside the grid repeat the nearest entry on the edge of the grid.
+    Clamp,
+    /// Positions outside the grid wrap around to the opposite edge, as on a torus.
+    Wrap,
+}
+
+impl Border {
+    /// Maps `position - radius` onto `0..len` according to this policy, where `position` is
+    /// expressed relative to `radius` so that it never needs to be negative.
+    fn resolve(self, position: usize, radius: usize, len: usize) -> Option<usize> {
+        if position < radius {
+            let before = radius - position;
+            match self {
+                Self::Ignore => None,
+                Self::Clamp => Some(0),
+                Self::Wrap => Some((len - before % len) % len),
+            }
+        } else if position - radius >= len {
+            match self {
+                Self::Ignore => None,
+                Self::Clamp => Some(len - 1),
+                Self::Wrap => Some((position - radius) % len),
+            }
+        } else {
+            Some(position - radius)
+        }
+    }
+}
+
+/// A view of the square neighborhood of `2 * radius + 1` by `2 * radius + 1` entries centered on
+/// an entry in a two-dimensional type. Positions within the neighborhood are given as
+/// `(row, column)` relative to its top-left corner, so the center is at `(radius, radius)`.
+///
+/// This `struct` is passed to the closure given to
+/// [`Vec2D::map_neighborhood`](super::Vec2D::map_neighborhood).
+#[derive(Debug, Clone, Copy)]
+pub struct Neighborhood<'a, T> {
+    raw: &'a [T],
+    rows: usize,
+    columns: usize,
+    stride: usize,
+    row: usize,
+    column: usize,
+    radius: usize,
+    border: Border,
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    pub(super) fn new(
+        raw: &'a [T],
+        (rows, columns, stride): (usize, usize, usize),
+        (row, column): (usize, usize),
+        radius: usize,
+        border: Border,
+    ) -> Self {
+        Self {
+            raw,
+            rows,
+            columns,
+            stride,
+            row,
+            column,
+            radius,
+            border,
+        }
+    }
+
+    /// Returns the number of rows and columns spanned by this neighborhood, i.e.,
+    /// `2 * radius + 1`.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        2 * self.radius + 1
+    }
+
+    /// Returns the entry in the center of this neighborhood.
+    #[must_use]
+    pub fn center(&self) -> &'a T {
+        &self.raw[self.row * self.stride + self.column]
+    }
+
+    /// Returns the entry at `row`, `column` within this neighborhood, or [`None`] if the position
+    /// is outside the neighborhood or was dropped by [`Border::Ignore`].
+    #[must_use]
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        if row >= self.size() || column >= self.size() {
+            return None;
+        }
+
+        let row = self
+            .border
+            .resolve(self.row + row, self.radius, self.rows)?;
+        let column = self
+            .border
+            .resolve(self.column + column, self.radius, self.columns)?;
+        self.raw.get(row * self.stride + column)
+    }
+
+    /// Returns an iterator over the entries of this neighborhood in reading order, including the
+    /// center, skipping any positions dropped by [`Border::Ignore`].
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        let size = self.size();
+        (0..size).flat_map(move |row| (0..size).filter_map(move |column| self.get(row, column)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn border_policies_resolve_positions() {
+        // A 1x4 grid; the neighborhood of column 0 with radius 2 spans columns -2..=2.
+        let raw = [0, 1, 2, 3];
+        let at = |border| {
+            let hood = Neighborhood::new(&raw, (1, 4, 4), (0, 0), 2, border);
+            (0..5).map(|x| hood.get(2, x).copied()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(at(Border::Ignore), [None, None, Some(0), Some(1), Some(2)]);
+        assert_eq!(
+            at(Border::Clamp),
+            [Some(0), Some(0), Some(0), Some(1), Some(2)]
+        );
+        assert_eq!(
+            at(Border::Wrap),
+            [Some(2), Some(3), Some(0), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn wraps_more_than_once_around_small_grids() {
+        let raw = [5];
+        let hood = Neighborhood::new(&raw, (1, 1, 1), (0, 0), 3, Border::Wrap);
+
+        assert_eq!(hood.iter().count(), 49);
+        assert!(hood.iter().all(|&n| n == 5));
+        assert_eq!(hood.get(7, 0), None);
+    }
+}
@@ -1,10 +1,18 @@
-use std::ops::{Index, IndexMut};
+use super::display::DisplayGrid;
+use super::iter::{EnumerateCells, EnumerateCellsMut, Iter, IterMut};
+use super::neighbors::{Neighbors, NEIGHBORS_4, NEIGHBORS_8};
+use super::{bounded_range, rows_of, ShapeError};
+use std::ops::{Deref, DerefMut, Index, IndexMut, RangeBounds};
 
 /// This struct represents a two-dimensional window into a one-dimensional slice. This is
 /// accomplished through taking either a `columns` parameter, and dividing the size of the slice
 /// evenly into `rows` based on its length, or by taking `rows` and `columns` directly, trusting
 /// that the caller provided correct values. The latter option provides a zero-cost abstraction.
 ///
+/// A `Window2D` can also be given a row stride larger than its number of columns, which lets it
+/// view a rectangular part of a larger grid, such as a sub-rectangle of a framebuffer. See
+/// [`Window2D::new_ref_strided`] and [`Window2D::new_mut_strided`].
+///
 /// # Example
 /// ```
 /// # use ilyvion_util::multi_dimensional::Window2D;
@@ -22,6 +30,7 @@ pub struct Window2D<T> {
     raw: T,
     rows: usize,
     columns: usize,
+    stride: usize,
 }
 
 impl<'b, T> Window2D<&'b mut [T]> {
@@ -32,11 +41,47 @@ impl<'b, T> Window2D<&'b mut [T]> {
     /// If the length of `raw` cannot be divided evenly into `column`s
     pub fn new_mut(raw: &'b mut [T], columns: usize) -> Self {
         let rows = raw.len() / columns;
-        if raw.len() % columns != 0 {
+        if !raw.len().is_multiple_of(columns) {
             panic!("The length of raw must divide evenly into columns.");
         }
 
-        Self { raw, rows, columns }
+        Self {
+            raw,
+            rows,
+            columns,
+            stride: columns,
+        }
+    }
+
+    /// Creates a new `Window2D` with rows divided into `columns` length. (I.e., `w[rows][columns]`)
+    ///
+    /// # Errors
+    ///
+    /// If `columns` is zero, [`ShapeError::ZeroColumns`] is returned. If the length of `raw`
+    /// cannot be divided evenly into `column`s, [`ShapeError::UnevenLength`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::{ShapeError, Window2D};
+    /// let mut values = [0; 6];
+    /// let mut window = Window2D::try_new_mut(&mut values, 2).unwrap();
+    /// window[2][1] = 1;
+    ///
+    /// assert_eq!(values, [0, 0, 0, 0, 0, 1]);
+    /// assert_eq!(
+    ///     Window2D::try_new_mut(&mut values, 4).unwrap_err(),
+    ///     ShapeError::UnevenLength { len: 6, columns: 4 }
+    /// );
+    /// ```
+    pub fn try_new_mut(raw: &'b mut [T], columns: usize) -> Result<Self, ShapeError> {
+        let rows = rows_of(raw.len(), columns)?;
+
+        Ok(Self {
+            raw,
+            rows,
+            columns,
+            stride: columns,
+        })
     }
 
     /// Creates a new `Window2D` divided into `rows` number of slices with `columns` entries each.
@@ -46,7 +91,41 @@ impl<'b, T> Window2D<&'b mut [T]> {
     ///
     /// Using this constructor gives you an essentially zero-cost abstraction.  
     pub fn new_mut_unchecked(raw: &'b mut [T], rows: usize, columns: usize) -> Self {
-        Self { raw, rows, columns }
+        Self {
+            raw,
+            rows,
+            columns,
+            stride: columns,
+        }
+    }
+
+    /// Creates a new `Window2D` of `rows` number of slices with `columns` entries each, where
+    /// each row starts `stride` entries after the start of the previous one. The entries between
+    /// the end of one row and the start of the next are not part of the window.
+    ///
+    /// # Panics
+    ///
+    /// If `stride < columns`, or if `raw` is too short to hold all the rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let mut framebuffer = [0u8; 16];
+    /// // The 2x2 square in the middle of a 4x4 framebuffer
+    /// let mut window = Window2D::new_mut_strided(&mut framebuffer[5..], 2, 2, 4);
+    /// window.fill(1);
+    ///
+    /// assert_eq!(framebuffer, [0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn new_mut_strided(raw: &'b mut [T], rows: usize, columns: usize, stride: usize) -> Self {
+        check_stride(raw.len(), rows, columns, stride);
+
+        Self {
+            raw,
+            rows,
+            columns,
+            stride,
+        }
     }
 }
 
@@ -58,11 +137,46 @@ impl<'b, T> Window2D<&'b [T]> {
     /// If the length of `raw` cannot be divided evenly into `column`s
     pub fn new_ref(raw: &'b [T], columns: usize) -> Self {
         let rows = raw.len() / columns;
-        if raw.len() % columns != 0 {
+        if !raw.len().is_multiple_of(columns) {
             panic!("The length of raw must divide evenly into columns.");
         }
 
-        Self { raw, rows, columns }
+        Self {
+            raw,
+            rows,
+            columns,
+            stride: columns,
+        }
+    }
+
+    /// Creates a new `Window2D` with rows divided into `columns` length. (I.e., `w[rows][columns]`)
+    ///
+    /// # Errors
+    ///
+    /// If `columns` is zero, [`ShapeError::ZeroColumns`] is returned. If the length of `raw`
+    /// cannot be divided evenly into `column`s, [`ShapeError::UnevenLength`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::{ShapeError, Window2D};
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let window = Window2D::try_new_ref(&values, 2).unwrap();
+    /// assert_eq!(window[2], [5, 6]);
+    ///
+    /// assert_eq!(
+    ///     Window2D::try_new_ref(&values, 0).unwrap_err(),
+    ///     ShapeError::ZeroColumns
+    /// );
+    /// ```
+    pub fn try_new_ref(raw: &'b [T], columns: usize) -> Result<Self, ShapeError> {
+        let rows = rows_of(raw.len(), columns)?;
+
+        Ok(Self {
+            raw,
+            rows,
+            columns,
+            stride: columns,
+        })
     }
 
     /// Creates a new `Window2D` divided into `rows` number of slices with `columns` entries each.
@@ -72,7 +186,363 @@ impl<'b, T> Window2D<&'b [T]> {
     ///
     /// Using this constructor gives you an essentially zero-cost abstraction.
     pub fn new_ref_unchecked(raw: &'b [T], rows: usize, columns: usize) -> Self {
-        Self { raw, rows, columns }
+        Self {
+            raw,
+            rows,
+            columns,
+            stride: columns,
+        }
+    }
+
+    /// Creates a new `Window2D` of `rows` number of slices with `columns` entries each, where
+    /// each row starts `stride` entries after the start of the previous one. The entries between
+    /// the end of one row and the start of the next are not part of the window.
+    ///
+    /// # Panics
+    ///
+    /// If `stride < columns`, or if `raw` is too short to hold all the rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let framebuffer: Vec<_> = (0..16).collect();
+    /// // The 2x2 square in the middle of a 4x4 framebuffer
+    /// let window = Window2D::new_ref_strided(&framebuffer[5..], 2, 2, 4);
+    ///
+    /// assert_eq!(window[0], [5, 6]);
+    /// assert_eq!(window[1], [9, 10]);
+    /// ```
+    pub fn new_ref_strided(raw: &'b [T], rows: usize, columns: usize, stride: usize) -> Self {
+        check_stride(raw.len(), rows, columns, stride);
+
+        Self {
+            raw,
+            rows,
+            columns,
+            stride,
+        }
+    }
+}
+
+fn check_stride(len: usize, rows: usize, columns: usize, stride: usize) {
+    assert!(
+        stride >= columns,
+        "The stride must be at least as large as columns."
+    );
+    let needed = match rows {
+        0 => 0,
+        _ => (rows - 1)
+            .checked_mul(stride)
+            .and_then(|n| n.checked_add(columns))
+            .expect("(rows - 1) * stride + columns > usize::MAX"),
+    };
+    assert!(
+        needed <= len,
+        "The length of raw is too short for the given rows."
+    );
+}
+
+impl<T, R: Deref<Target = [T]>> Window2D<R> {
+    /// Returns a reference to the entry at `row`, `column`, or [`None`] if either is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let values = [0, 1, 2, 3];
+    /// let window = Window2D::new_ref(&values, 2);
+    ///
+    /// assert_eq!(window.get(1, 0), Some(&2));
+    /// assert_eq!(window.get(0, 2), None);
+    /// assert_eq!(window.get(2, 0), None);
+    /// ```
+    #[must_use]
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.rows && column < self.columns {
+            self.raw.get(row * self.stride + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over all the entries in reading order, yielding the row and column of
+    /// each entry along with a reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let values = ['a', 'b', 'c', 'd'];
+    /// let window = Window2D::new_ref(&values, 2);
+    ///
+    /// let cells: Vec<_> = window.enumerate_cells().collect();
+    /// assert_eq!(cells, [(0, 0, &'a'), (0, 1, &'b'), (1, 0, &'c'), (1, 1, &'d')]);
+    /// ```
+    #[must_use]
+    pub fn enumerate_cells(&self) -> EnumerateCells<'_, T> {
+        EnumerateCells::new(&self.raw, self.rows, self.columns, self.stride)
+    }
+
+    /// Returns an iterator over all the entries in reading order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let values = [3, 1, 4, 1, 5, 9, 2, 6];
+    /// let window = Window2D::new_ref_strided(&values, 2, 3, 4);
+    ///
+    /// assert_eq!(window.iter().max(), Some(&9));
+    /// assert_eq!(window.iter().sum::<i32>(), 24);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.raw, self.rows, self.columns, self.stride)
+    }
+
+    /// Returns an object that implements [`Display`](std::fmt::Display) by writing the result of
+    /// calling `func` on each entry, one row per line. Cells are padded to the width of the widest
+    /// cell in their column; the separator and alignment can be configured on the returned
+    /// [`DisplayGrid`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// use std::fmt::Alignment;
+    ///
+    /// let values = [1, -2, 30, 4];
+    /// let grid = Window2D::new_ref(&values, 2);
+    ///
+    /// assert_eq!(grid.display_with(|n| *n).to_string(), " 1 -2\n30  4");
+    /// assert_eq!(
+    ///     grid.display_with(|n| n * 2)
+    ///         .separator(", ")
+    ///         .align(Alignment::Left)
+    ///         .to_string(),
+    ///     "2 , -4\n60, 8"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display_with<F, D>(&self, func: F) -> DisplayGrid<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: std::fmt::Display,
+    {
+        DisplayGrid::new(&self.raw, (self.rows, self.columns, self.stride), func)
+    }
+
+    /// Returns an iterator over the in-bounds entries directly above, to the left of, to the right
+    /// of and below `row`, `column` (i.e. its von Neumann neighborhood), in that order. Each entry
+    /// is yielded as its `(row, column)` along with a reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let values = [0, 1, 2, 3, 4, 5];
+    /// let window = Window2D::new_ref(&values, 3);
+    ///
+    /// let neighbors: Vec<_> = window.neighbors4(0, 1).collect();
+    /// assert_eq!(neighbors, [((0, 0), &0), ((0, 2), &2), ((1, 1), &4)]);
+    /// ```
+    #[must_use]
+    pub fn neighbors4(&self, row: usize, column: usize) -> Neighbors<'_, T> {
+        Neighbors::new(
+            &self.raw,
+            (self.rows, self.columns, self.stride),
+            (row, column),
+            NEIGHBORS_4,
+        )
+    }
+
+    /// Returns an iterator over the in-bounds entries surrounding `row`, `column`, diagonals
+    /// included (i.e. its Moore neighborhood), in reading order. Each entry is yielded as its
+    /// `(row, column)` along with a reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let values = [0, 1, 2, 3, 4, 5];
+    /// let window = Window2D::new_ref(&values, 3);
+    ///
+    /// let neighbors: Vec<_> = window.neighbors8(1, 0).map(|(_, &n)| n).collect();
+    /// assert_eq!(neighbors, [0, 1, 4]);
+    /// ```
+    #[must_use]
+    pub fn neighbors8(&self, row: usize, column: usize) -> Neighbors<'_, T> {
+        Neighbors::new(
+            &self.raw,
+            (self.rows, self.columns, self.stride),
+            (row, column),
+            NEIGHBORS_8,
+        )
+    }
+}
+
+impl<T, R: DerefMut<Target = [T]>> Window2D<R> {
+    /// Returns a mutable reference to the entry at `row`, `column`, or [`None`] if either is out
+    /// of bounds.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row < self.rows && column < self.columns {
+            self.raw.get_mut(row * self.stride + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over all the entries in reading order, yielding the row and column of
+    /// each entry along with a mutable reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let mut values = [0; 4];
+    /// let mut window = Window2D::new_mut(&mut values, 2);
+    /// for (y, x, entry) in window.enumerate_cells_mut() {
+    ///     *entry = 10 * y + x;
+    /// }
+    ///
+    /// assert_eq!(values, [0, 1, 10, 11]);
+    /// ```
+    pub fn enumerate_cells_mut(&mut self) -> EnumerateCellsMut<'_, T> {
+        EnumerateCellsMut::new(&mut self.raw, self.rows, self.columns, self.stride)
+    }
+
+    /// Returns an iterator over mutable references to all the entries in reading order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let mut values = [1, 2, 3, 4];
+    /// let mut window = Window2D::new_mut(&mut values, 2);
+    /// window.iter_mut().for_each(|n| *n *= 2);
+    ///
+    /// assert_eq!(values, [2, 4, 6, 8]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&mut self.raw, self.rows, self.columns, self.stride)
+    }
+
+    /// Fills every entry with clones of `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.fill_region(.., .., value);
+    }
+
+    /// Fills every entry with values returned by calling `func` repeatedly.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, mut func: F) {
+        self.enumerate_cells_mut()
+            .for_each(|(_, _, entry)| *entry = func());
+    }
+
+    /// Fills the entries in the rectangle spanned by `rows` and `columns` with clones of `value`.
+    ///
+    /// # Panics
+    ///
+    /// If either range starts after it ends or extends beyond the bounds of this `Window2D`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let mut values = [0; 9];
+    /// let mut window = Window2D::new_mut(&mut values, 3);
+    /// window.fill_region(1.., ..2, 7);
+    ///
+    /// assert_eq!(values, [0, 0, 0, 7, 7, 0, 7, 7, 0]);
+    /// ```
+    pub fn fill_region<RR, CR>(&mut self, rows: RR, columns: CR, value: T)
+    where
+        RR: RangeBounds<usize>,
+        CR: RangeBounds<usize>,
+        T: Clone,
+    {
+        let columns = bounded_range(columns, self.columns);
+        for row in bounded_range(rows, self.rows) {
+            let start = row * self.stride;
+            self.raw[start..][columns.clone()].fill(value.clone());
+        }
+    }
+
+    /// Swaps the entries at `a` and `b`, given as `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// If either position is outside the bounds of this `Window2D`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let mut values = [0, 1, 2, 3, 4, 5];
+    /// let mut v = Window2D::new_mut(&mut values, 3);
+    /// v.swap((0, 0), (1, 2));
+    ///
+    /// assert_eq!(values, [5, 1, 2, 3, 4, 0]);
+    /// ```
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        assert!(a.0 < self.rows && a.1 < self.columns, "a is out of bounds");
+        assert!(b.0 < self.rows && b.1 < self.columns, "b is out of bounds");
+        self.raw
+            .swap(a.0 * self.stride + a.1, b.0 * self.stride + b.1);
+    }
+
+    /// Swaps the rows at indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If either index is outside the bounds of this `Window2D`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Window2D;
+    /// let mut values = [0, 1, 2, 3, 4, 5];
+    /// let mut v = Window2D::new_mut(&mut values, 3);
+    /// v.swap_rows(0, 1);
+    ///
+    /// assert_eq!(values, [3, 4, 5, 0, 1, 2]);
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows, "a is out of bounds");
+        assert!(b < self.rows, "b is out of bounds");
+        let (low, high) = (a.min(b), a.max(b));
+        if low != high {
+            let columns = self.columns;
+            let (top, bottom) = self.raw.split_at_mut(high * self.stride);
+            top[low * self.stride..][..columns].swap_with_slice(&mut bottom[..columns]);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for Window2D<&'a [T]> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::new(self.raw, self.rows, self.columns, self.stride)
+    }
+}
+
+impl<'a, T> IntoIterator for Window2D<&'a mut [T]> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::new(self.raw, self.rows, self.columns, self.stride)
+    }
+}
+
+impl<'a, T: 'a, R: Deref<Target = [T]>> IntoIterator for &'a Window2D<R> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: 'a, R: DerefMut<Target = [T]>> IntoIterator for &'a mut Window2D<R> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
@@ -81,7 +551,7 @@ impl<T> Index<usize> for Window2D<&'_ [T]> {
 
     fn index(&self, row: usize) -> &Self::Output {
         assert!(row < self.rows);
-        &self.raw[row * self.columns..][..self.columns]
+        &self.raw[row * self.stride..][..self.columns]
     }
 }
 
@@ -98,14 +568,14 @@ impl<T> Index<usize> for Window2D<&'_ mut [T]> {
 
     fn index(&self, row: usize) -> &Self::Output {
         assert!(row < self.rows);
-        &self.raw[row * self.columns..][..self.columns]
+        &self.raw[row * self.stride..][..self.columns]
     }
 }
 
 impl<T> IndexMut<usize> for Window2D<&'_ mut [T]> {
     fn index_mut(&mut self, row: usize) -> &mut Self::Output {
         assert!(row < self.rows);
-        &mut self.raw[row * self.columns..][..self.columns]
+        &mut self.raw[row * self.stride..][..self.columns]
     }
 }
 
@@ -172,4 +642,60 @@ mod tests {
         assert_eq!(window_mut[(3, 0)], 2);
         assert_eq!(window_mut[(3, 1)], 0);
     }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let mut values = [0, 1, 2, 3, 4, 5];
+        let mut window = Window2D::new_mut(&mut values, 3);
+
+        assert_eq!(window.get(1, 2), Some(&5));
+        assert_eq!(window.get(0, 3), None);
+        assert_eq!(window.get(2, 0), None);
+
+        *window.get_mut(0, 1).unwrap() = 10;
+        assert_eq!(window.get_mut(1, 3), None);
+        assert_eq!(values, [0, 10, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn strided_windows_only_touch_their_columns() {
+        let mut values: Vec<_> = (0..12).collect();
+        let mut window = Window2D::new_mut_strided(&mut values[1..], 3, 2, 4);
+
+        assert_eq!(window[(1, 1)], 6);
+        assert_eq!(window.get(2, 1), Some(&10));
+        assert_eq!(window.get(0, 2), None);
+        let neighbors: Vec<_> = window.neighbors8(1, 0).map(|(_, &n)| n).collect();
+        assert_eq!(neighbors, [1, 2, 6, 9, 10]);
+        assert_eq!(window.display_with(|n| *n).to_string(), "1  2\n5  6\n9 10");
+
+        window.swap_rows(0, 2);
+        window.swap((0, 0), (1, 1));
+        assert_eq!(values, [0, 6, 10, 3, 4, 5, 9, 7, 8, 1, 2, 11]);
+
+        window = Window2D::new_mut_strided(&mut values[1..], 3, 2, 4);
+        window.fill_with(|| 0);
+        window[2][0] = 1;
+        assert_eq!(values, [0, 0, 0, 3, 4, 0, 0, 7, 8, 1, 0, 11]);
+    }
+
+    #[test]
+    fn into_iterator_works_for_all_windows() {
+        let mut values = [1, 2, 3, 4, 5, 6];
+
+        for n in &mut Window2D::new_mut_strided(&mut values, 2, 2, 3) {
+            *n += 10;
+        }
+        let window = Window2D::new_ref(&values, 3);
+        assert_eq!((&window).into_iter().count(), 6);
+        let collected: Vec<_> = window.into_iter().copied().collect();
+        assert_eq!(collected, [11, 12, 3, 14, 15, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "too short")]
+    fn strided_window_must_fit() {
+        let values = [0; 8];
+        let _ = Window2D::new_ref_strided(&values, 3, 2, 4);
+    }
 }
@@ -0,0 +1,195 @@
+use std::ops::{Index, IndexMut};
+
+/// This struct represents a three-dimensional window into a one-dimensional slice. This is
+/// accomplished through taking either `rows` and `columns` parameters, and dividing the size of
+/// the slice evenly into `depth` layers based on its length, or by taking `depth`, `rows` and
+/// `columns` directly, trusting that the caller provided correct values. The latter option
+/// provides a zero-cost abstraction.
+///
+/// # Example
+/// ```
+/// # use ilyvion_util::multi_dimensional::Window3D;
+/// let mut values = [0u32; 8];
+/// let mut window = Window3D::new_mut(&mut values, 2, 2);
+/// window[(0, 0, 1)] = 1;
+/// window[(0, 1, 1)] = 2;
+/// window[(1, 0, 1)] = 3;
+/// window[(1, 1)][1] = 4;
+///
+/// assert_eq!(values, [0, 1, 0, 2, 0, 3, 0, 4]);
+/// ```
+#[derive(Debug)]
+pub struct Window3D<T> {
+    raw: T,
+    depth: usize,
+    rows: usize,
+    columns: usize,
+}
+
+impl<'b, T> Window3D<&'b mut [T]> {
+    /// Creates a new `Window3D` with layers of `rows` rows that are `columns` long. (I.e.,
+    /// `w[(depth, rows, columns)]`)
+    ///
+    /// # Panics
+    ///
+    /// If `rows` or `columns` is zero, or if the length of `raw` cannot be divided evenly into
+    /// layers of `rows * columns` entries.
+    pub fn new_mut(raw: &'b mut [T], rows: usize, columns: usize) -> Self {
+        let depth = depth_of(raw.len(), rows, columns);
+
+        Self {
+            raw,
+            depth,
+            rows,
+            columns,
+        }
+    }
+
+    /// Creates a new `Window3D` divided into `depth` layers of `rows` number of slices with
+    /// `columns` entries each.
+    ///
+    /// Providing incorrect values for `depth`, `rows` and `columns` will most likely lead to
+    /// run-time panics due to indexing outside the range of the slice.
+    ///
+    /// Using this constructor gives you an essentially zero-cost abstraction.
+    pub fn new_mut_unchecked(raw: &'b mut [T], depth: usize, rows: usize, columns: usize) -> Self {
+        Self {
+            raw,
+            depth,
+            rows,
+            columns,
+        }
+    }
+}
+
+impl<'b, T> Window3D<&'b [T]> {
+    /// Creates a new `Window3D` with layers of `rows` rows that are `columns` long. (I.e.,
+    /// `w[(depth, rows, columns)]`)
+    ///
+    /// # Panics
+    ///
+    /// If `rows` or `columns` is zero, or if the length of `raw` cannot be divided evenly into
+    /// layers of `rows * columns` entries.
+    pub fn new_ref(raw: &'b [T], rows: usize, columns: usize) -> Self {
+        let depth = depth_of(raw.len(), rows, columns);
+
+        Self {
+            raw,
+            depth,
+            rows,
+            columns,
+        }
+    }
+
+    /// Creates a new `Window3D` divided into `depth` layers of `rows` number of slices with
+    /// `columns` entries each.
+    ///
+    /// Providing incorrect values for `depth`, `rows` and `columns` will most likely lead to
+    /// run-time panics due to indexing outside the range of the slice.
+    ///
+    /// Using this constructor gives you an essentially zero-cost abstraction.
+    pub fn new_ref_unchecked(raw: &'b [T], depth: usize, rows: usize, columns: usize) -> Self {
+        Self {
+            raw,
+            depth,
+            rows,
+            columns,
+        }
+    }
+}
+
+pub(super) fn depth_of(len: usize, rows: usize, columns: usize) -> usize {
+    let layer = rows
+        .checked_mul(columns)
+        .expect("rows * columns > usize::MAX");
+    assert!(layer > 0, "rows and columns must both be non-zero.");
+    if !len.is_multiple_of(layer) {
+        panic!("The length of raw must divide evenly into rows * columns.");
+    }
+
+    len / layer
+}
+
+impl<T> Index<(usize, usize)> for Window3D<&'_ [T]> {
+    type Output = [T];
+
+    fn index(&self, (layer, row): (usize, usize)) -> &Self::Output {
+        assert!(layer < self.depth && row < self.rows);
+        &self.raw[(layer * self.rows + row) * self.columns..][..self.columns]
+    }
+}
+
+impl<T> Index<(usize, usize, usize)> for Window3D<&'_ [T]> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize, usize)) -> &Self::Output {
+        &self[(index.0, index.1)][index.2]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Window3D<&'_ mut [T]> {
+    type Output = [T];
+
+    fn index(&self, (layer, row): (usize, usize)) -> &Self::Output {
+        assert!(layer < self.depth && row < self.rows);
+        &self.raw[(layer * self.rows + row) * self.columns..][..self.columns]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Window3D<&'_ mut [T]> {
+    fn index_mut(&mut self, (layer, row): (usize, usize)) -> &mut Self::Output {
+        assert!(layer < self.depth && row < self.rows);
+        &mut self.raw[(layer * self.rows + row) * self.columns..][..self.columns]
+    }
+}
+
+impl<T> Index<(usize, usize, usize)> for Window3D<&'_ mut [T]> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize, usize)) -> &Self::Output {
+        &self[(index.0, index.1)][index.2]
+    }
+}
+
+impl<T> IndexMut<(usize, usize, usize)> for Window3D<&'_ mut [T]> {
+    fn index_mut(&mut self, index: (usize, usize, usize)) -> &mut Self::Output {
+        &mut self[(index.0, index.1)][index.2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexing_works_correctly() {
+        let mut values: Vec<_> = (0..12).collect();
+        let window = Window3D::new_ref(&values, 2, 3);
+
+        assert_eq!(window[(0, 0)], [0, 1, 2]);
+        assert_eq!(window[(0, 1)], [3, 4, 5]);
+        assert_eq!(window[(1, 0)], [6, 7, 8]);
+        assert_eq!(window[(1, 1)], [9, 10, 11]);
+        assert_eq!(window[(1, 0, 2)], 8);
+
+        let mut window_mut = Window3D::new_mut_unchecked(&mut values, 2, 2, 3);
+        window_mut[(1, 1, 0)] *= 10;
+        window_mut[(0, 1)][2] = 0;
+
+        assert_eq!(values, [0, 1, 2, 3, 4, 0, 6, 7, 8, 90, 10, 11]);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide evenly")]
+    fn uneven_length_panics() {
+        let values = [0; 10];
+        let _ = Window3D::new_ref(&values, 2, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero")]
+    fn zero_sized_layer_panics() {
+        let values: [i32; 0] = [];
+        let _ = Window3D::new_ref(&values, 0, 3);
+    }
+}
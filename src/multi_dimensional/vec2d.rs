@@ -1,4 +1,10 @@
-use std::ops::{Index, IndexMut};
+use super::display::DisplayGrid;
+use super::iter::{EnumerateCells, EnumerateCellsMut, IntoRows};
+use super::neighbors::{Border, Neighborhood, Neighbors, NEIGHBORS_4, NEIGHBORS_8};
+use super::ShapeError;
+use super::{bounded_range, rows_of};
+use std::ops::{Index, IndexMut, RangeBounds};
+use std::{slice, vec};
 
 /// This struct represents a two-dimensional window into a one-dimensional `Vec`. This is
 /// accomplished through taking either a `columns` parameter, and dividing the size of the `Vec`
@@ -61,13 +67,77 @@ impl<T> Vec2D<T> {
     #[must_use]
     pub fn from(raw: Vec<T>, columns: usize) -> Self {
         let rows = raw.len() / columns;
-        if raw.len() % columns != 0 {
+        if !raw.len().is_multiple_of(columns) {
             panic!("The length of raw must divide evenly into columns.");
         }
 
         Self { raw, rows, columns }
     }
 
+    /// Creates a new `Vec2D` with rows divided into `columns` length. (I.e., `w[rows][columns]`)
+    ///
+    /// # Errors
+    ///
+    /// If `columns` is zero, [`ShapeError::ZeroColumns`] is returned. If the length of `raw`
+    /// cannot be divided evenly into `column`s, [`ShapeError::UnevenLength`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::{ShapeError, Vec2D};
+    /// let v = Vec2D::try_from(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// assert_eq!((v.rows(), v.columns()), (2, 3));
+    ///
+    /// assert_eq!(
+    ///     Vec2D::try_from(vec![1, 2, 3, 4, 5], 3).unwrap_err(),
+    ///     ShapeError::UnevenLength { len: 5, columns: 3 }
+    /// );
+    /// ```
+    pub fn try_from(raw: Vec<T>, columns: usize) -> Result<Self, ShapeError> {
+        let rows = rows_of(raw.len(), columns)?;
+
+        Ok(Self { raw, rows, columns })
+    }
+
+    /// Creates a new `Vec2D` from a [`Vec`] of rows, flattening them in order.
+    ///
+    /// # Errors
+    ///
+    /// If the rows don't all have the same length, [`ShapeError::RaggedRow`] is returned for the
+    /// first row whose length differs from the ones before it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::{ShapeError, Vec2D};
+    /// let v = Vec2D::try_from_nested(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!((v.rows(), v.columns()), (3, 2));
+    /// assert_eq!(v.into_inner(), [1, 2, 3, 4, 5, 6]);
+    ///
+    /// let ragged = Vec2D::try_from_nested(vec![vec![1, 2], vec![3]]);
+    /// assert_eq!(
+    ///     ragged.unwrap_err(),
+    ///     ShapeError::RaggedRow { row: 1, expected: 2, found: 1 }
+    /// );
+    /// ```
+    pub fn try_from_nested(nested: Vec<Vec<T>>) -> Result<Self, ShapeError> {
+        let rows = nested.len();
+        let columns = nested.first().map_or(0, Vec::len);
+        if let Some((row, found)) = nested
+            .iter()
+            .map(Vec::len)
+            .enumerate()
+            .find(|&(_, len)| len != columns)
+        {
+            return Err(ShapeError::RaggedRow {
+                row,
+                expected: columns,
+                found,
+            });
+        }
+
+        let raw = nested.into_iter().flatten().collect();
+        Ok(Self { raw, rows, columns })
+    }
+
     /// Creates a new `Vec2D` divided into `rows` number of slices with `columns` entries each.
     ///
     /// Providing incorrect values for `rows` and `columns` will most likely lead to run-time panics
@@ -79,11 +149,693 @@ impl<T> Vec2D<T> {
         Self { raw, rows, columns }
     }
 
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns, i.e. the length of each row.
+    #[must_use]
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns a reference to the entry at `row`, `column`, or [`None`] if either is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![0, 1, 2, 3], 2);
+    ///
+    /// assert_eq!(v.get(1, 0), Some(&2));
+    /// assert_eq!(v.get(0, 2), None);
+    /// assert_eq!(v.get(2, 0), None);
+    /// ```
+    #[must_use]
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.rows && column < self.columns {
+            self.raw.get(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the entry at `row`, `column`, or [`None`] if either is out
+    /// of bounds.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row < self.rows && column < self.columns {
+            self.raw.get_mut(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over all the entries in reading order, yielding the row and column of
+    /// each entry along with a reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec!['a', 'b', 'c', 'd'], 2);
+    ///
+    /// let cells: Vec<_> = v.enumerate_cells().collect();
+    /// assert_eq!(cells, [(0, 0, &'a'), (0, 1, &'b'), (1, 0, &'c'), (1, 1, &'d')]);
+    /// ```
+    #[must_use]
+    pub fn enumerate_cells(&self) -> EnumerateCells<'_, T> {
+        EnumerateCells::new(&self.raw, self.rows, self.columns, self.columns)
+    }
+
+    /// Returns an iterator over all the entries in reading order, yielding the row and column of
+    /// each entry along with a mutable reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::new(2, 2);
+    /// for (y, x, entry) in v.enumerate_cells_mut() {
+    ///     *entry = 10 * y + x;
+    /// }
+    ///
+    /// assert_eq!(v.into_inner(), [0, 1, 10, 11]);
+    /// ```
+    pub fn enumerate_cells_mut(&mut self) -> EnumerateCellsMut<'_, T> {
+        EnumerateCellsMut::new(&mut self.raw, self.rows, self.columns, self.columns)
+    }
+
+    /// Returns an iterator over all the entries in reading order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![3, 1, 4, 1, 5, 9], 3);
+    ///
+    /// assert_eq!(v.iter().sum::<i32>(), 23);
+    /// assert_eq!(v.iter().position(|&n| n == 5), Some(4));
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.raw.iter()
+    }
+
+    /// Returns an iterator over mutable references to all the entries in reading order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::from(vec![1, 2, 3, 4], 2);
+    /// v.iter_mut().for_each(|n| *n *= 2);
+    ///
+    /// assert_eq!(v.into_inner(), [2, 4, 6, 8]);
+    /// ```
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.raw.iter_mut()
+    }
+
+    /// Creates a new `Vec2D` of the same shape as this one, with the result of calling `func` on
+    /// each respective entry.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4], 2);
+    /// let doubled = v.map(|n| n * 2);
+    ///
+    /// assert_eq!(doubled[1], [6, 8]);
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, func: F) -> Vec2D<U> {
+        Vec2D {
+            raw: self.raw.iter().map(func).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+
+    /// Creates a new `Vec2D` of the same shape as this one, with the result of calling `func` on
+    /// the row, column and value of each respective entry.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4], 2);
+    /// let mapped = v.map_indexed(|y, x, n| 100 * y + 10 * x + n);
+    ///
+    /// assert_eq!(mapped.into_inner(), [1, 12, 103, 114]);
+    /// ```
+    pub fn map_indexed<U, F: FnMut(usize, usize, &T) -> U>(&self, mut func: F) -> Vec2D<U> {
+        Vec2D {
+            raw: self
+                .enumerate_cells()
+                .map(|(y, x, entry)| func(y, x, entry))
+                .collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+
+    /// Creates a new `Vec2D` of the same shape as this one, with the result of calling `func` on
+    /// each entry along with its [`Neighborhood`] of `2 * radius + 1` by `2 * radius + 1`
+    /// entries. `border` decides what the neighborhood contains where it extends beyond the
+    /// edges of this `Vec2D`.
+    ///
+    /// This is useful for convolutions such as blurs, and for cellular automata.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::{Border, Vec2D};
+    /// let v = Vec2D::from(vec![0, 0, 0, 0, 9, 0, 0, 0, 0], 3);
+    ///
+    /// // A 3x3 box blur
+    /// let blurred = v.map_neighborhood(1, Border::Clamp, |_, hood| hood.iter().sum::<i32>() / 9);
+    /// assert!(blurred.iter().all(|&n| n == 1));
+    ///
+    /// // Count the live neighbors of each cell
+    /// let live = v.map_neighborhood(1, Border::Ignore, |&cell, hood| {
+    ///     hood.iter().filter(|&&n| n > 0).count() - usize::from(cell > 0)
+    /// });
+    /// assert_eq!(live.into_inner(), [1, 1, 1, 1, 0, 1, 1, 1, 1]);
+    /// ```
+    pub fn map_neighborhood<U, F>(&self, radius: usize, border: Border, mut func: F) -> Vec2D<U>
+    where
+        F: FnMut(&T, Neighborhood<'_, T>) -> U,
+    {
+        let shape = (self.rows, self.columns, self.columns);
+        Vec2D {
+            raw: self
+                .enumerate_cells()
+                .map(|(y, x, entry)| {
+                    func(
+                        entry,
+                        Neighborhood::new(&self.raw, shape, (y, x), radius, border),
+                    )
+                })
+                .collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+
+    /// Returns an object that implements [`Display`](std::fmt::Display) by writing the result of
+    /// calling `func` on each entry, one row per line. Cells are padded to the width of the widest
+    /// cell in their column; the separator and alignment can be configured on the returned
+    /// [`DisplayGrid`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// use std::fmt::Alignment;
+    ///
+    /// let grid = Vec2D::from(vec![1, -2, 30, 4], 2);
+    ///
+    /// assert_eq!(grid.display_with(|n| *n).to_string(), " 1 -2\n30  4");
+    /// assert_eq!(
+    ///     grid.display_with(|n| n * 2)
+    ///         .separator(", ")
+    ///         .align(Alignment::Left)
+    ///         .to_string(),
+    ///     "2 , -4\n60, 8"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display_with<F, D>(&self, func: F) -> DisplayGrid<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: std::fmt::Display,
+    {
+        DisplayGrid::new(&self.raw, (self.rows, self.columns, self.columns), func)
+    }
+
+    /// Fills every entry with clones of `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.raw.fill(value);
+    }
+
+    /// Fills every entry with values returned by calling `func` repeatedly.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, func: F) {
+        self.raw.fill_with(func);
+    }
+
+    /// Fills the entries in the rectangle spanned by `rows` and `columns` with clones of `value`.
+    ///
+    /// # Panics
+    ///
+    /// If either range starts after it ends or extends beyond the bounds of this `Vec2D`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::new(3, 3);
+    /// v.fill_region(1.., ..2, 7);
+    ///
+    /// assert_eq!(v.into_inner(), [0, 0, 0, 7, 7, 0, 7, 7, 0]);
+    /// ```
+    pub fn fill_region<RR, CR>(&mut self, rows: RR, columns: CR, value: T)
+    where
+        RR: RangeBounds<usize>,
+        CR: RangeBounds<usize>,
+        T: Clone,
+    {
+        let columns = bounded_range(columns, self.columns);
+        for row in bounded_range(rows, self.rows) {
+            let start = row * self.columns;
+            self.raw[start..][columns.clone()].fill(value.clone());
+        }
+    }
+
+    /// Swaps the entries at `a` and `b`, given as `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// If either position is outside the bounds of this `Vec2D`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::from(vec![0, 1, 2, 3, 4, 5], 3);
+    /// v.swap((0, 0), (1, 2));
+    ///
+    /// assert_eq!(v.into_inner(), [5, 1, 2, 3, 4, 0]);
+    /// ```
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        assert!(a.0 < self.rows && a.1 < self.columns, "a is out of bounds");
+        assert!(b.0 < self.rows && b.1 < self.columns, "b is out of bounds");
+        self.raw
+            .swap(a.0 * self.columns + a.1, b.0 * self.columns + b.1);
+    }
+
+    /// Swaps the rows at indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If either index is outside the bounds of this `Vec2D`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::from(vec![0, 1, 2, 3, 4, 5], 3);
+    /// v.swap_rows(0, 1);
+    ///
+    /// assert_eq!(v.into_inner(), [3, 4, 5, 0, 1, 2]);
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows, "a is out of bounds");
+        assert!(b < self.rows, "b is out of bounds");
+        let (low, high) = (a.min(b), a.max(b));
+        if low != high {
+            let columns = self.columns;
+            let (top, bottom) = self.raw.split_at_mut(high * self.columns);
+            top[low * self.columns..][..columns].swap_with_slice(&mut bottom[..columns]);
+        }
+    }
+
+    /// Returns an iterator over the in-bounds entries directly above, to the left of, to the right
+    /// of and below `row`, `column` (i.e. its von Neumann neighborhood), in that order. Each entry
+    /// is yielded as its `(row, column)` along with a reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![0, 1, 2, 3, 4, 5], 3);
+    ///
+    /// let neighbors: Vec<_> = v.neighbors4(0, 1).collect();
+    /// assert_eq!(neighbors, [((0, 0), &0), ((0, 2), &2), ((1, 1), &4)]);
+    /// ```
+    #[must_use]
+    pub fn neighbors4(&self, row: usize, column: usize) -> Neighbors<'_, T> {
+        Neighbors::new(
+            &self.raw,
+            (self.rows, self.columns, self.columns),
+            (row, column),
+            NEIGHBORS_4,
+        )
+    }
+
+    /// Returns an iterator over the in-bounds entries surrounding `row`, `column`, diagonals
+    /// included (i.e. its Moore neighborhood), in reading order. Each entry is yielded as its
+    /// `(row, column)` along with a reference to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![0, 1, 2, 3, 4, 5], 3);
+    ///
+    /// let neighbors: Vec<_> = v.neighbors8(1, 0).map(|(_, &n)| n).collect();
+    /// assert_eq!(neighbors, [0, 1, 4]);
+    /// ```
+    #[must_use]
+    pub fn neighbors8(&self, row: usize, column: usize) -> Neighbors<'_, T> {
+        Neighbors::new(
+            &self.raw,
+            (self.rows, self.columns, self.columns),
+            (row, column),
+            NEIGHBORS_8,
+        )
+    }
+
+    /// Swaps the rows and columns of this `Vec2D`, such that the entry at `[y][x]` ends up at
+    /// `[x][y]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4, 5, 6], 3).transpose();
+    ///
+    /// assert_eq!((v.rows(), v.columns()), (3, 2));
+    /// assert_eq!(v.into_inner(), [1, 4, 2, 5, 3, 6]);
+    /// ```
+    #[must_use]
+    pub fn transpose(mut self) -> Self {
+        self.transpose_in_place();
+        self
+    }
+
+    /// Swaps the rows and columns of this `Vec2D` in place, such that the entry at `[y][x]` ends
+    /// up at `[x][y]`.
+    ///
+    /// Square grids are transposed by swapping entries across the diagonal. Other shapes have
+    /// their entries swapped along the cycles of the transposition, which needs one bit of
+    /// bookkeeping per entry, but doesn't require `T: Clone`.
+    pub fn transpose_in_place(&mut self) {
+        let (rows, columns) = (self.rows, self.columns);
+        if rows == columns {
+            for y in 0..rows {
+                for x in y + 1..columns {
+                    self.raw.swap(y * columns + x, x * columns + y);
+                }
+            }
+        } else {
+            // The index in the original layout of the entry that belongs at `index` once
+            // transposed.
+            let source = |index: usize| index % rows * columns + index / rows;
+
+            let mut visited = vec![false; self.raw.len()];
+            for start in 0..self.raw.len() {
+                if visited[start] {
+                    continue;
+                }
+
+                let mut current = start;
+                loop {
+                    visited[current] = true;
+                    let next = source(current);
+                    if next == start {
+                        break;
+                    }
+                    self.raw.swap(current, next);
+                    current = next;
+                }
+            }
+        }
+
+        self.rows = columns;
+        self.columns = rows;
+    }
+
+    /// Rotates this `Vec2D` a quarter turn clockwise, such that the first column, read from the
+    /// bottom up, becomes the first row.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4, 5, 6], 3).rotate_cw();
+    ///
+    /// assert_eq!((v.rows(), v.columns()), (3, 2));
+    /// assert_eq!(v.into_inner(), [4, 1, 5, 2, 6, 3]);
+    /// ```
+    #[must_use]
+    pub fn rotate_cw(mut self) -> Self {
+        self.rotate_cw_in_place();
+        self
+    }
+
+    /// Rotates this `Vec2D` a quarter turn clockwise in place. See [`Vec2D::rotate_cw`].
+    pub fn rotate_cw_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_horizontal_in_place();
+    }
+
+    /// Rotates this `Vec2D` a quarter turn counterclockwise, such that the last column, read from
+    /// the top down, becomes the first row.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4, 5, 6], 3).rotate_ccw();
+    ///
+    /// assert_eq!((v.rows(), v.columns()), (3, 2));
+    /// assert_eq!(v.into_inner(), [3, 6, 2, 5, 1, 4]);
+    /// ```
+    #[must_use]
+    pub fn rotate_ccw(mut self) -> Self {
+        self.rotate_ccw_in_place();
+        self
+    }
+
+    /// Rotates this `Vec2D` a quarter turn counterclockwise in place. See [`Vec2D::rotate_ccw`].
+    pub fn rotate_ccw_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_vertical_in_place();
+    }
+
+    /// Mirrors this `Vec2D` left to right, reversing the order of the entries in each row.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4, 5, 6], 3).flip_horizontal();
+    ///
+    /// assert_eq!(v.into_inner(), [3, 2, 1, 6, 5, 4]);
+    /// ```
+    #[must_use]
+    pub fn flip_horizontal(mut self) -> Self {
+        self.flip_horizontal_in_place();
+        self
+    }
+
+    /// Mirrors this `Vec2D` left to right in place. See [`Vec2D::flip_horizontal`].
+    pub fn flip_horizontal_in_place(&mut self) {
+        if self.columns > 0 {
+            self.raw
+                .chunks_exact_mut(self.columns)
+                .for_each(<[T]>::reverse);
+        }
+    }
+
+    /// Mirrors this `Vec2D` top to bottom, reversing the order of the rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4, 5, 6], 3).flip_vertical();
+    ///
+    /// assert_eq!(v.into_inner(), [4, 5, 6, 1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn flip_vertical(mut self) -> Self {
+        self.flip_vertical_in_place();
+        self
+    }
+
+    /// Mirrors this `Vec2D` top to bottom in place. See [`Vec2D::flip_vertical`].
+    pub fn flip_vertical_in_place(&mut self) {
+        let columns = self.columns;
+        for y in 0..self.rows / 2 {
+            let (top, bottom) = self.raw.split_at_mut((self.rows - 1 - y) * columns);
+            top[y * columns..][..columns].swap_with_slice(&mut bottom[..columns]);
+        }
+    }
+
+    /// Appends a row to the bottom of this `Vec2D`.
+    ///
+    /// If this `Vec2D` has no rows, the length of `row` becomes its number of columns.
+    ///
+    /// # Panics
+    ///
+    /// If this `Vec2D` has rows and the length of `row` is not equal to its number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::from(vec![1, 2], 2);
+    /// v.push_row(vec![3, 4]);
+    ///
+    /// assert_eq!(v.rows(), 2);
+    /// assert_eq!(v[1], [3, 4]);
+    /// ```
+    pub fn push_row<I: IntoIterator<Item = T>>(&mut self, row: I) {
+        self.insert_row(self.rows, row);
+    }
+
+    /// Inserts a row at position `index`, shifting all rows after it downwards.
+    ///
+    /// If this `Vec2D` has no rows, the length of `row` becomes its number of columns.
+    ///
+    /// # Panics
+    ///
+    /// If `index > rows`, or if this `Vec2D` has rows and the length of `row` is not equal to its
+    /// number of columns.
+    pub fn insert_row<I: IntoIterator<Item = T>>(&mut self, index: usize, row: I) {
+        assert!(index <= self.rows, "row index out of bounds");
+        let row: Vec<_> = row.into_iter().collect();
+        if self.rows == 0 {
+            self.columns = row.len();
+        }
+        assert_eq!(row.len(), self.columns, "row length must equal columns");
+
+        let at = index * self.columns;
+        let _ = self.raw.splice(at..at, row);
+        self.rows += 1;
+    }
+
+    /// Removes and returns the row at position `index`, shifting all rows after it upwards.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= rows`.
+    pub fn remove_row(&mut self, index: usize) -> Vec<T> {
+        assert!(index < self.rows, "row index out of bounds");
+        let at = index * self.columns;
+        let row = self.raw.drain(at..at + self.columns).collect();
+        self.rows -= 1;
+
+        row
+    }
+
+    /// Appends a column to the right side of this `Vec2D`, the first entry of `column` ending up
+    /// in the first row.
+    ///
+    /// If this `Vec2D` has no columns, the length of `column` becomes its number of rows.
+    ///
+    /// # Panics
+    ///
+    /// If this `Vec2D` has columns and the length of `column` is not equal to its number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::from(vec![1, 2], 1);
+    /// v.push_column(vec![3, 4]);
+    ///
+    /// assert_eq!(v.columns(), 2);
+    /// assert_eq!(v.into_inner(), [1, 3, 2, 4]);
+    /// ```
+    pub fn push_column<I: IntoIterator<Item = T>>(&mut self, column: I) {
+        self.insert_column(self.columns, column);
+    }
+
+    /// Inserts a column at position `index`, shifting all columns after it to the right.
+    ///
+    /// If this `Vec2D` has no columns, the length of `column` becomes its number of rows.
+    ///
+    /// # Panics
+    ///
+    /// If `index > columns`, or if this `Vec2D` has columns and the length of `column` is not
+    /// equal to its number of rows.
+    pub fn insert_column<I: IntoIterator<Item = T>>(&mut self, index: usize, column: I) {
+        assert!(index <= self.columns, "column index out of bounds");
+        let column: Vec<_> = column.into_iter().collect();
+        if self.columns == 0 {
+            self.rows = column.len();
+        }
+        assert_eq!(column.len(), self.rows, "column length must equal rows");
+
+        let mut old = std::mem::take(&mut self.raw).into_iter();
+        self.raw.reserve_exact(self.rows * (self.columns + 1));
+        for entry in column {
+            self.raw.extend(old.by_ref().take(index));
+            self.raw.push(entry);
+            self.raw.extend(old.by_ref().take(self.columns - index));
+        }
+        self.columns += 1;
+    }
+
+    /// Removes and returns the column at position `index`, shifting all columns after it to the
+    /// left.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= columns`.
+    pub fn remove_column(&mut self, index: usize) -> Vec<T> {
+        assert!(index < self.columns, "column index out of bounds");
+        let columns = self.columns;
+        let old = std::mem::take(&mut self.raw);
+        self.raw.reserve_exact(self.rows * (columns - 1));
+
+        let mut column = Vec::with_capacity(self.rows);
+        for (i, entry) in old.into_iter().enumerate() {
+            if i % columns == index {
+                column.push(entry);
+            } else {
+                self.raw.push(entry);
+            }
+        }
+        self.columns -= 1;
+
+        column
+    }
+
+    /// Resizes this `Vec2D` to `rows` rows of `columns` entries each. Entries that are within
+    /// bounds of both the old and the new size keep their position; entries outside the new size
+    /// are dropped, and newly added entries are set to clones of `value`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let mut v = Vec2D::from(vec![1, 2, 3, 4, 5, 6], 3);
+    /// v.resize(3, 2, 0);
+    ///
+    /// assert_eq!(v.into_inner(), [1, 2, 4, 5, 0, 0]);
+    /// ```
+    pub fn resize(&mut self, rows: usize, columns: usize, value: T)
+    where
+        T: Clone,
+    {
+        if columns == self.columns {
+            self.raw.resize(rows * columns, value);
+            self.rows = rows;
+            return;
+        }
+
+        let kept_rows = rows.min(self.rows);
+        let kept_columns = columns.min(self.columns);
+        let mut old = std::mem::take(&mut self.raw).into_iter();
+        self.raw.reserve_exact(rows * columns);
+        for _ in 0..kept_rows {
+            self.raw.extend(old.by_ref().take(kept_columns));
+            old.by_ref()
+                .take(self.columns - kept_columns)
+                .for_each(drop);
+            self.raw
+                .extend(std::iter::repeat_n(value.clone(), columns - kept_columns));
+        }
+        self.raw.resize(rows * columns, value);
+        self.rows = rows;
+        self.columns = columns;
+    }
+
     /// Unwraps this `Vec2D<T>`, returning the underlying [`Vec`].
     #[must_use]
     pub fn into_inner(self) -> Vec<T> {
         self.raw
     }
+
+    /// Consumes this `Vec2D`, returning an iterator that yields each row as a [`Vec`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec2D;
+    /// let v = Vec2D::from(vec![1, 2, 3, 4, 5, 6], 3);
+    ///
+    /// let rows: Vec<Vec<_>> = v.into_rows().collect();
+    /// assert_eq!(rows, [vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    #[must_use]
+    pub fn into_rows(self) -> IntoRows<T> {
+        IntoRows::new(self.raw, self.rows, self.columns)
+    }
 }
 
 impl<T: Default> Vec2D<T> {
@@ -99,6 +851,33 @@ impl<T: Default> Vec2D<T> {
     }
 }
 
+impl<T> IntoIterator for Vec2D<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.raw.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec2D<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vec2D<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<T> Index<usize> for Vec2D<T> {
     type Output = [T];
 
@@ -128,3 +907,116 @@ impl<T> IndexMut<(usize, usize)> for Vec2D<T> {
         &mut self[index.0][index.1]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transpose_any_shape() {
+        for rows in 0..6 {
+            for columns in 1..6 {
+                let v = Vec2D::new_with(rows, columns, |y, x| (y, x));
+                let t = v.transpose();
+
+                assert_eq!((t.rows(), t.columns()), (columns, rows));
+                for y in 0..columns {
+                    for x in 0..rows {
+                        assert_eq!(t[(y, x)], (x, y));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn row_and_column_insertion_and_removal() {
+        let mut v = Vec2D::new_with(0, 0, |_, _| 0);
+        v.push_row(vec![1, 2, 3]);
+        v.push_row(vec![7, 8, 9]);
+        v.insert_row(1, vec![4, 5, 6]);
+        assert_eq!((v.rows(), v.columns()), (3, 3));
+        assert_eq!(v.raw, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        v.insert_column(0, vec![0, 0, 0]);
+        v.push_column(vec![10, 20, 30]);
+        assert_eq!((v.rows(), v.columns()), (3, 5));
+        assert_eq!(v.raw, [0, 1, 2, 3, 10, 0, 4, 5, 6, 20, 0, 7, 8, 9, 30]);
+
+        assert_eq!(v.remove_column(2), [2, 5, 8]);
+        assert_eq!(v.remove_row(0), [0, 1, 3, 10]);
+        assert_eq!((v.rows(), v.columns()), (2, 4));
+        assert_eq!(v.raw, [0, 4, 6, 20, 0, 7, 9, 30]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row length must equal columns")]
+    fn push_row_of_wrong_length_panics() {
+        let mut v = Vec2D::from(vec![1, 2], 2);
+        v.push_row(vec![3]);
+    }
+
+    #[test]
+    fn enumerate_cells_from_both_ends() {
+        let v = Vec2D::new_with(2, 3, |y, x| 10 * y + x);
+
+        let mut cells = v.enumerate_cells();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells.next_back(), Some((1, 2, &12)));
+        assert_eq!(cells.next(), Some((0, 0, &0)));
+        assert!(cells.all(|(y, x, &n)| n == 10 * y + x));
+    }
+
+    #[test]
+    fn fill_operations() {
+        let mut v = Vec2D::new(3, 4);
+        v.fill(1);
+        v.fill_region(0..=1, 1..3, 2);
+        v.fill_region(.., 3.., 3);
+        assert_eq!(v.raw, [1, 2, 2, 3, 1, 2, 2, 3, 1, 1, 1, 3]);
+
+        let mut counter = 0;
+        v.fill_with(|| {
+            counter += 1;
+            counter
+        });
+        assert_eq!(v.raw, (1..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn fill_region_out_of_bounds_panics() {
+        let mut v = Vec2D::new(2, 2);
+        v.fill_region(.., 1..3, 1);
+    }
+
+    #[test]
+    fn rotations_and_flips_compose() {
+        let original = Vec2D::new_with(3, 4, |y, x| (y, x));
+
+        let three_quarters = original.map(|&p| p).rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!((three_quarters.rows(), three_quarters.columns()), (4, 3));
+        assert_eq!(three_quarters[(0, 0)], (0, 3));
+        assert_eq!(three_quarters.rotate_cw().raw, original.raw);
+
+        let flipped = original.map(|&p| p).flip_vertical().flip_horizontal();
+        let half_turn = original.map(|&p| p).rotate_ccw().rotate_ccw();
+        assert_eq!(flipped.raw, half_turn.raw);
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let v = Vec2D::new_with(3, 3, |y, x| y * 3 + x);
+
+        let center: Vec<_> = v.neighbors8(1, 1).map(|(_, &n)| n).collect();
+        assert_eq!(center, [0, 1, 2, 3, 5, 6, 7, 8]);
+
+        let corner: Vec<_> = v.neighbors8(2, 2).map(|(p, _)| p).collect();
+        assert_eq!(corner, [(1, 1), (1, 2), (2, 1)]);
+
+        let top_left: Vec<_> = v.neighbors4(0, 0).map(|(p, _)| p).collect();
+        assert_eq!(top_left, [(0, 1), (1, 0)]);
+
+        assert_eq!(v.neighbors4(5, 5).count(), 0);
+    }
+}
@@ -0,0 +1,157 @@
+use super::slice3d::depth_of;
+use std::ops::{Index, IndexMut};
+
+/// This struct represents a three-dimensional window into a one-dimensional `Vec`. This is
+/// accomplished through taking either `rows` and `columns` parameters, and dividing the size of
+/// the `Vec` evenly into `depth` layers based on its length, or by taking `depth`, `rows` and
+/// `columns` directly, trusting that the caller provided correct values. The latter option
+/// provides a zero-cost abstraction.
+///
+/// # Example
+/// ```
+/// # use ilyvion_util::multi_dimensional::Vec3D;
+/// let mut window = Vec3D::from(vec![0u32; 8], 2, 2);
+/// window[(0, 0, 1)] = 1;
+/// window[(0, 1, 1)] = 2;
+/// window[(1, 0, 1)] = 3;
+/// window[(1, 1)][1] = 4;
+///
+/// let values = window.into_inner();
+///
+/// assert_eq!(values, [0, 1, 0, 2, 0, 3, 0, 4]);
+/// ```
+#[derive(Debug)]
+pub struct Vec3D<T> {
+    raw: Vec<T>,
+    depth: usize,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Vec3D<T> {
+    /// Creates a new `Vec3D` with `depth` layers of `rows` rows that are `columns` long (i.e.,
+    /// `w[(depth, rows, columns)]`) placing the result of `func` in each respective entry.
+    ///
+    /// # Panics
+    ///
+    /// If `depth * rows * columns > usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ilyvion_util::multi_dimensional::Vec3D;
+    /// let v = Vec3D::new_with(2, 1, 2, |z, y, x| 100 * z + 10 * y + x);
+    /// let values = v.into_inner();
+    ///
+    /// assert_eq!(values, [0, 1, 100, 101]);
+    /// ```
+    pub fn new_with<F>(depth: usize, rows: usize, columns: usize, mut func: F) -> Self
+    where
+        F: FnMut(usize, usize, usize) -> T,
+    {
+        let layer = rows.checked_mul(columns);
+        let len = layer
+            .and_then(|layer| layer.checked_mul(depth))
+            .expect("depth * rows * columns > usize::MAX");
+        let layer = layer.unwrap();
+        let raw: Vec<_> = (0..len)
+            .map(|count| {
+                func(
+                    count / layer,
+                    count % layer / columns,
+                    count % layer % columns,
+                )
+            })
+            .collect();
+        Self {
+            raw,
+            depth,
+            rows,
+            columns,
+        }
+    }
+
+    /// Creates a new `Vec3D` with layers of `rows` rows that are `columns` long. (I.e.,
+    /// `w[(depth, rows, columns)]`)
+    ///
+    /// # Panics
+    ///
+    /// If `rows` or `columns` is zero, or if the length of `raw` cannot be divided evenly into
+    /// layers of `rows * columns` entries.
+    #[must_use]
+    pub fn from(raw: Vec<T>, rows: usize, columns: usize) -> Self {
+        let depth = depth_of(raw.len(), rows, columns);
+
+        Self {
+            raw,
+            depth,
+            rows,
+            columns,
+        }
+    }
+
+    /// Creates a new `Vec3D` divided into `depth` layers of `rows` number of slices with
+    /// `columns` entries each.
+    ///
+    /// Providing incorrect values for `depth`, `rows` and `columns` will most likely lead to
+    /// run-time panics due to indexing outside the range of the [`Vec`].
+    ///
+    /// Using this constructor gives you an essentially zero-cost abstraction.
+    #[must_use]
+    pub fn from_unchecked(raw: Vec<T>, depth: usize, rows: usize, columns: usize) -> Self {
+        Self {
+            raw,
+            depth,
+            rows,
+            columns,
+        }
+    }
+
+    /// Unwraps this `Vec3D<T>`, returning the underlying [`Vec`].
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.raw
+    }
+}
+
+impl<T: Default> Vec3D<T> {
+    /// Creates a new `Vec3D` with `depth` layers of `rows` rows that are `columns` long (i.e.,
+    /// `w[(depth, rows, columns)]`) with `T::default()` in every entry.
+    ///
+    /// # Panics
+    ///
+    /// If `depth * rows * columns > usize::MAX`.
+    #[must_use]
+    pub fn new(depth: usize, rows: usize, columns: usize) -> Self {
+        Self::new_with(depth, rows, columns, |_, _, _| T::default())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Vec3D<T> {
+    type Output = [T];
+
+    fn index(&self, (layer, row): (usize, usize)) -> &Self::Output {
+        assert!(layer < self.depth && row < self.rows);
+        &self.raw.as_slice()[(layer * self.rows + row) * self.columns..][..self.columns]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Vec3D<T> {
+    fn index_mut(&mut self, (layer, row): (usize, usize)) -> &mut Self::Output {
+        assert!(layer < self.depth && row < self.rows);
+        &mut self.raw.as_mut_slice()[(layer * self.rows + row) * self.columns..][..self.columns]
+    }
+}
+
+impl<T> Index<(usize, usize, usize)> for Vec3D<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize, usize)) -> &Self::Output {
+        &self[(index.0, index.1)][index.2]
+    }
+}
+
+impl<T> IndexMut<(usize, usize, usize)> for Vec3D<T> {
+    fn index_mut(&mut self, index: (usize, usize, usize)) -> &mut Self::Output {
+        &mut self[(index.0, index.1)][index.2]
+    }
+}
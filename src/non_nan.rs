@@ -1,54 +1,986 @@
-//! A trait and a type useful for dealing with types with a `NaN` value.
+//! A trait and types useful for dealing with types with a `NaN` value.
 //! This is `f32` and `f64`.
 
 use shrinkwraprs::Shrinkwrap;
 use std::cmp::Ordering;
-use std::fmt::Debug;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Trait that lets you generalize over types that have a NaN.
 pub trait NanType: Copy + Clone + Default + Debug + PartialOrd + PartialEq {
     /// Returns `true` if this value is `NaN`.
     fn is_nan(self) -> bool;
+    /// Returns `true` if this value is neither infinite nor `NaN`.
+    fn is_finite(self) -> bool;
+    /// Returns the ordering between `self` and `other` according to the IEEE 754 `totalOrder`
+    /// predicate.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    /// Feeds the bit pattern of this value into the given [`Hasher`]. Two values have the same bit
+    /// pattern exactly when [`NanType::total_cmp`] considers them equal.
+    fn hash_bits<H: Hasher>(self, state: &mut H);
 }
 impl NanType for f32 {
     fn is_nan(self) -> bool {
         self.is_nan()
     }
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+    fn hash_bits<H: Hasher>(self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
 }
 impl NanType for f64 {
     fn is_nan(self) -> bool {
         self.is_nan()
     }
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+    fn hash_bits<H: Hasher>(self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
 }
 
 /// A type that wraps a `NanType` with the guarantee that its contained value is not
 /// `NaN`.
+///
+/// Arithmetic on `NonNan` values re-checks the result, since even non-`NaN` operands can produce
+/// `NaN` (e.g. `0 / 0` or `∞ - ∞`). The binary operators therefore return a
+/// `Result<NonNan<T>, NanError>`, while the assigning operators panic if the result is `NaN`.
+/// Negation can never produce `NaN`, so it returns a `NonNan<T>` directly. The same goes for the
+/// math methods on `NonNan<f32>` and `NonNan<f64>`: those that can't produce `NaN` (like `abs` and
+/// `powi`) return a `NonNan`, while those that can (like `sqrt` and `ln`) return a `Result`.
+///
+/// With the `serde` feature enabled, this type serializes as its contained value, and fails to
+/// deserialize if the value is `NaN`.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::non_nan::{NanError, NonNan};
+/// let mut total = NonNan::new(1.0);
+/// total += NonNan::new(2.0);
+/// assert_eq!(total, NonNan::new(3.0));
+/// assert_eq!(-total, NonNan::new(-3.0));
+/// assert_eq!(total / NonNan::new(2.0), Ok(NonNan::new(1.5)));
+///
+/// let zero = NonNan::new(0.0);
+/// assert_eq!(zero / zero, Err(NanError));
+///
+/// assert_eq!(NonNan::new(-4.0_f64).abs().sqrt(), Ok(NonNan::new(2.0)));
+/// assert_eq!(NonNan::new(-4.0_f64).sqrt(), Err(NanError));
+/// assert_eq!(NonNan::new(0.0_f64).ln(), Ok(NonNan::new(f64::NEG_INFINITY)));
+/// ```
 #[derive(PartialEq, PartialOrd, Shrinkwrap, Copy, Clone, Default, Debug)]
 pub struct NonNan<T: NanType>(T);
 
 impl<T: NanType> NonNan<T> {
-    /// Creates a new `NonNan<T>`.
+    /// Creates a new `NonNan<T>`. This is a convenience for when `val` is known not to be `NaN`;
+    /// use [`NonNan::try_new`] to validate untrusted values.
     ///
     /// # Panics
     ///
     /// If `val.is_nan()` is `true`.
     pub fn new(val: T) -> Self {
-        assert!(!val.is_nan(), "NaN values are not allowed");
+        Self::try_new(val).expect("NaN values are not allowed")
+    }
+
+    /// Creates a new `NonNan<T>`, or returns a [`NanError`] if `val` is `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// If `val.is_nan()` is `true`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::{NanError, NonNan};
+    /// # use std::convert::TryFrom;
+    /// assert_eq!(NonNan::try_new(1.5).map(|n| *n), Ok(1.5));
+    /// assert_eq!(NonNan::try_new(f64::NAN), Err(NanError));
+    /// assert_eq!(NonNan::<f32>::try_from(f32::NAN), Err(NanError));
+    /// ```
+    pub fn try_new(val: T) -> Result<Self, NanError> {
+        if val.is_nan() {
+            Err(NanError)
+        } else {
+            Ok(Self(val))
+        }
+    }
+
+    /// Creates a new `NonNan<T>` without checking for `NaN` in release builds. Meant for hot paths
+    /// where `val` is already known not to be `NaN`; wrapping a `NaN` anyway breaks the
+    /// guarantees of this type, e.g. [`Ord::cmp`] will panic.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `val.is_nan()` is `true`.
+    pub fn new_unchecked(val: T) -> Self {
+        debug_assert!(!val.is_nan(), "NaN values are not allowed");
         Self(val)
     }
+
+    /// Creates a new `NonNan<T>` with `val` clamped to the range `min..=max`, for sanitizing input
+    /// rather than rejecting it. A `NaN` `val` is treated as being below the range, and so becomes
+    /// `min`.
+    ///
+    /// # Panics
+    ///
+    /// If `min > max`, or if either `min` or `max` is `NaN`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::NonNan;
+    /// assert_eq!(NonNan::new_clamped(1.5, 0.0, 1.0), NonNan::new(1.0));
+    /// assert_eq!(NonNan::new_clamped(-0.5, 0.0, 1.0), NonNan::new(0.0));
+    /// assert_eq!(NonNan::new_clamped(0.25, 0.0, 1.0), NonNan::new(0.25));
+    /// assert_eq!(NonNan::new_clamped(f64::NAN, 0.0, 1.0), NonNan::new(0.0));
+    /// ```
+    pub fn new_clamped(val: T, min: T, max: T) -> Self {
+        let min = Self::new(min);
+        let max = Self::new(max);
+        assert!(min <= max, "min must be less than or equal to max");
+        match Self::try_new(val) {
+            Ok(val) => val.clamp(min, max),
+            Err(_) => min,
+        }
+    }
+
+    /// Creates a new `NonNan<T>`, substituting `default` if `val` is `NaN`, for sanitizing input
+    /// rather than rejecting it.
+    ///
+    /// # Panics
+    ///
+    /// If both `val` and `default` are `NaN`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::NonNan;
+    /// assert_eq!(NonNan::new_or(2.5, 0.0), NonNan::new(2.5));
+    /// assert_eq!(NonNan::new_or(f32::NAN, 0.0), NonNan::new(0.0));
+    /// ```
+    pub fn new_or(val: T, default: T) -> Self {
+        Self::try_new(val).unwrap_or_else(|_| Self::new(default))
+    }
+
+    /// Consumes the `NonNan<T>`, returning the wrapped value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::NonNan;
+    /// assert_eq!(NonNan::new(2.5).into_inner(), 2.5);
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
 }
 
-impl<T: NanType> Eq for NonNan<T> {}
+/// A type that wraps a `NanType` with the guarantee that its contained value is finite, i.e.
+/// neither infinite nor `NaN`.
+///
+/// Arithmetic on `Finite` values re-checks the result, since finite operands can overflow to an
+/// infinity or produce `NaN` (e.g. `0 / 0`). The binary operators therefore return a
+/// `Result<Finite<T>, NonFiniteError>`, while the assigning operators panic if the result isn't
+/// finite. Negation always produces a finite value, so it returns a `Finite<T>` directly.
+///
+/// With the `serde` feature enabled, this type serializes as its contained value, and fails to
+/// deserialize if the value is infinite or `NaN`.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::non_nan::{Finite, NonFiniteError};
+/// let mut total = Finite::new(1.0);
+/// total *= Finite::new(4.0);
+/// assert_eq!(total, Finite::new(4.0));
+/// assert_eq!(-total, Finite::new(-4.0));
+///
+/// assert_eq!(Finite::new(f64::MAX) * Finite::new(2.0), Err(NonFiniteError));
+/// assert_eq!(Finite::new(1.0) / Finite::new(0.0), Err(NonFiniteError));
+/// ```
+#[derive(PartialEq, PartialOrd, Shrinkwrap, Copy, Clone, Default, Debug)]
+pub struct Finite<T: NanType>(T);
 
-#[allow(clippy::derive_ord_xor_partial_ord)]
-impl<T: NanType> Ord for NonNan<T> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+impl<T: NanType> Finite<T> {
+    /// Creates a new `Finite<T>`. This is a convenience for when `val` is known to be finite;
+    /// use [`Finite::try_new`] to validate untrusted values.
+    ///
+    /// # Panics
+    ///
+    /// If `val.is_finite()` is `false`.
+    pub fn new(val: T) -> Self {
+        Self::try_new(val).expect("infinite and NaN values are not allowed")
+    }
+
+    /// Creates a new `Finite<T>`, or returns a [`NonFiniteError`] if `val` is infinite or `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// If `val.is_finite()` is `false`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::{Finite, NonFiniteError};
+    /// # use std::convert::TryFrom;
+    /// assert_eq!(Finite::try_new(1.5).map(|n| *n), Ok(1.5));
+    /// assert_eq!(Finite::try_new(f64::INFINITY), Err(NonFiniteError));
+    /// assert_eq!(Finite::<f32>::try_from(f32::NAN), Err(NonFiniteError));
+    /// ```
+    pub fn try_new(val: T) -> Result<Self, NonFiniteError> {
+        if val.is_finite() {
+            Ok(Self(val))
+        } else {
+            Err(NonFiniteError)
+        }
+    }
+
+    /// Creates a new `Finite<T>` without checking for infinities or `NaN` in release builds. Meant
+    /// for hot paths where `val` is already known to be finite; wrapping a non-finite value anyway
+    /// breaks the guarantees of this type, e.g. [`Ord::cmp`] will panic on `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `val.is_finite()` is `false`.
+    pub fn new_unchecked(val: T) -> Self {
+        debug_assert!(val.is_finite(), "infinite and NaN values are not allowed");
+        Self(val)
+    }
+
+    /// Consumes the `Finite<T>`, returning the wrapped value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::Finite;
+    /// assert_eq!(Finite::new(2.5).into_inner(), 2.5);
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
     }
 }
 
-impl<T: NanType> From<T> for NonNan<T> {
+// Trait methods can't be called in a `const fn`, so the const constructors are implemented for
+// each concrete float type instead of generically.
+macro_rules! const_constructors {
+    ($($ty:ident)+) => {
+        $(
+            impl NonNan<$ty> {
+                /// Creates a new `NonNan` in a `const` context, such as the initializer of a
+                /// `static`, where using a `NaN` becomes a compile-time error.
+                ///
+                /// # Panics
+                ///
+                /// If `val.is_nan()` is `true`.
+                ///
+                /// # Examples
+                /// ```rust
+                /// # use ilyvion_util::non_nan::NonNan;
+                /// static HALF: NonNan<f64> = NonNan::<f64>::new_const(0.5);
+                /// assert_eq!(*HALF, 0.5);
+                /// ```
+                #[must_use]
+                pub const fn new_const(val: $ty) -> Self {
+                    assert!(!val.is_nan(), "NaN values are not allowed");
+                    Self(val)
+                }
+            }
+
+            impl Finite<$ty> {
+                /// Creates a new `Finite` in a `const` context, such as the initializer of a
+                /// `static`, where using an infinity or a `NaN` becomes a compile-time error.
+                ///
+                /// # Panics
+                ///
+                /// If `val.is_finite()` is `false`.
+                ///
+                /// # Examples
+                /// ```rust
+                /// # use ilyvion_util::non_nan::Finite;
+                /// static HALF: Finite<f32> = Finite::<f32>::new_const(0.5);
+                /// assert_eq!(*HALF, 0.5);
+                /// ```
+                #[must_use]
+                pub const fn new_const(val: $ty) -> Self {
+                    assert!(val.is_finite(), "infinite and NaN values are not allowed");
+                    Self(val)
+                }
+            }
+        )+
+    };
+}
+
+const_constructors!(f32 f64);
+
+// These operations can't turn a non-NaN value into `NaN`; e.g. `powi` only produces infinities
+// (`0⁻¹`) or `1` (`∞⁰`) at the edges of its domain.
+macro_rules! closed_math {
+    ($($(#[$attr:meta])* $method:ident($($arg:ident: $arg_ty:ty),*);)+) => {
+        $(
+            $(#[$attr])*
+            #[must_use]
+            pub fn $method(self, $($arg: $arg_ty),*) -> Self {
+                Self(self.0.$method($($arg),*))
+            }
+        )+
+    };
+}
+
+// These operations produce `NaN` for some non-NaN inputs, e.g. the square root or logarithm of a
+// negative number, or the sine of an infinity.
+macro_rules! checked_math {
+    ($($(#[$attr:meta])* $method:ident($($arg:ident: $arg_ty:ty),*);)+) => {
+        $(
+            $(#[$attr])*
+            ///
+            /// # Errors
+            ///
+            /// If the result is `NaN`.
+            pub fn $method(self, $($arg: $arg_ty),*) -> Result<Self, NanError> {
+                Self::try_new(self.0.$method($($arg),*))
+            }
+        )+
+    };
+}
+
+macro_rules! math_passthroughs {
+    ($($ty:ident)+) => {
+        $(
+            impl NonNan<$ty> {
+                closed_math! {
+                    /// Computes the absolute value of `self`.
+                    abs();
+                    /// Returns a number that represents the sign of `self`.
+                    signum();
+                    /// Returns the largest integer less than or equal to `self`.
+                    floor();
+                    /// Returns the smallest integer greater than or equal to `self`.
+                    ceil();
+                    /// Returns the nearest integer to `self`. Half-way cases round away from `0.0`.
+                    round();
+                    /// Returns the integer part of `self`.
+                    trunc();
+                    /// Takes the reciprocal (inverse) of `self`.
+                    recip();
+                    /// Returns `e^(self)`.
+                    exp();
+                    /// Raises `self` to an integer power.
+                    powi(n: i32);
+                }
+
+                checked_math! {
+                    /// Returns the square root of `self`.
+                    sqrt();
+                    /// Returns the natural logarithm of `self`.
+                    ln();
+                    /// Returns the base 2 logarithm of `self`.
+                    log2();
+                    /// Returns the base 10 logarithm of `self`.
+                    log10();
+                    /// Raises `self` to a floating point power.
+                    powf(n: $ty);
+                    /// Computes the sine of `self` (in radians).
+                    sin();
+                    /// Computes the cosine of `self` (in radians).
+                    cos();
+                    /// Computes the tangent of `self` (in radians).
+                    tan();
+                }
+            }
+        )+
+    };
+}
+
+math_passthroughs!(f32 f64);
+
+impl<T: NanType> From<Finite<T>> for NonNan<T> {
+    fn from(finite: Finite<T>) -> Self {
+        Self(finite.0)
+    }
+}
+
+/// A type that wraps a `NanType` with the guarantee that its contained value is neither negative
+/// nor `NaN`, which suits quantities like lengths, durations and probabilities. Note that both
+/// `0.0` and `-0.0` are allowed, as is positive infinity.
+///
+/// Adding two non-negative values always produces a non-negative value, so `+` returns a
+/// `NonNegative<T>` directly. The other binary operators can leave the valid range (e.g. `1 - 2`
+/// or `0 * ∞`), so they return a `Result<NonNegative<T>, NegativeError>`, while their assigning
+/// variants panic if the result is invalid.
+///
+/// With the `serde` feature enabled, this type serializes as its contained value, and fails to
+/// deserialize if the value is negative or `NaN`.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::non_nan::{NegativeError, NonNan, NonNegative};
+/// let length = NonNegative::new(2.0) + NonNegative::new(0.5);
+/// assert_eq!(length, NonNegative::new(2.5));
+/// assert_eq!(NonNan::from(length), NonNan::new(2.5));
+///
+/// assert_eq!(NonNegative::try_new(-1.0), Err(NegativeError));
+/// assert_eq!(NonNegative::new(1.0) - NonNegative::new(2.0), Err(NegativeError));
+/// ```
+#[derive(PartialEq, PartialOrd, Shrinkwrap, Copy, Clone, Default, Debug)]
+pub struct NonNegative<T: NanType>(T);
+
+impl<T: NanType> NonNegative<T> {
+    /// Creates a new `NonNegative<T>`. This is a convenience for when `val` is known to be
+    /// non-negative; use [`NonNegative::try_new`] to validate untrusted values.
+    ///
+    /// # Panics
+    ///
+    /// If `val` is negative or `NaN`.
+    pub fn new(val: T) -> Self {
+        Self::try_new(val).expect("negative and NaN values are not allowed")
+    }
+
+    /// Creates a new `NonNegative<T>`, or returns a [`NegativeError`] if `val` is negative or
+    /// `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// If `val` is negative or `NaN`.
+    pub fn try_new(val: T) -> Result<Self, NegativeError> {
+        if val >= T::default() {
+            Ok(Self(val))
+        } else {
+            Err(NegativeError)
+        }
+    }
+
+    /// Creates a new `NonNegative<T>` without checking its value in release builds. Meant for hot
+    /// paths where `val` is already known to be non-negative; wrapping an invalid value anyway
+    /// breaks the guarantees of this type, e.g. [`Ord::cmp`] will panic on `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `val` is negative or `NaN`.
+    pub fn new_unchecked(val: T) -> Self {
+        debug_assert!(
+            val >= T::default(),
+            "negative and NaN values are not allowed"
+        );
+        Self(val)
+    }
+
+    /// Consumes the `NonNegative<T>`, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// A type that wraps a `NanType` with the guarantee that its contained value is greater than zero
+/// and not `NaN`. Positive infinity is allowed.
+///
+/// Adding two positive values always produces a positive value, so `+` returns a `Positive<T>`
+/// directly. The other binary operators can leave the valid range (e.g. `1 - 2`, `∞ / ∞`, or
+/// multiplying two tiny values until the result underflows to zero), so they return a
+/// `Result<Positive<T>, NonPositiveError>`, while their assigning variants panic if the result
+/// is invalid.
+///
+/// With the `serde` feature enabled, this type serializes as its contained value, and fails to
+/// deserialize if the value is zero, negative or `NaN`.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::non_nan::{NonNegative, NonPositiveError, Positive};
+/// let mut scale = Positive::new(2.0);
+/// scale *= Positive::new(1.5);
+/// assert_eq!(scale, Positive::new(3.0));
+/// assert_eq!(NonNegative::from(scale), NonNegative::new(3.0));
+///
+/// assert_eq!(Positive::try_new(0.0), Err(NonPositiveError));
+/// assert_eq!(Positive::new(f64::MIN_POSITIVE) * Positive::new(f64::MIN_POSITIVE), Err(NonPositiveError));
+/// ```
+#[derive(PartialEq, PartialOrd, Shrinkwrap, Copy, Clone, Debug)]
+pub struct Positive<T: NanType>(T);
+
+impl<T: NanType> Positive<T> {
+    /// Creates a new `Positive<T>`. This is a convenience for when `val` is known to be positive;
+    /// use [`Positive::try_new`] to validate untrusted values.
+    ///
+    /// # Panics
+    ///
+    /// If `val` is zero, negative or `NaN`.
+    pub fn new(val: T) -> Self {
+        Self::try_new(val).expect("zero, negative and NaN values are not allowed")
+    }
+
+    /// Creates a new `Positive<T>`, or returns a [`NonPositiveError`] if `val` is zero, negative
+    /// or `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// If `val` is zero, negative or `NaN`.
+    pub fn try_new(val: T) -> Result<Self, NonPositiveError> {
+        if val > T::default() {
+            Ok(Self(val))
+        } else {
+            Err(NonPositiveError)
+        }
+    }
+
+    /// Creates a new `Positive<T>` without checking its value in release builds. Meant for hot
+    /// paths where `val` is already known to be positive; wrapping an invalid value anyway breaks
+    /// the guarantees of this type, e.g. [`Ord::cmp`] will panic on `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `val` is zero, negative or `NaN`.
+    pub fn new_unchecked(val: T) -> Self {
+        debug_assert!(
+            val > T::default(),
+            "zero, negative and NaN values are not allowed"
+        );
+        Self(val)
+    }
+
+    /// Consumes the `Positive<T>`, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: NanType> From<NonNegative<T>> for NonNan<T> {
+    fn from(non_negative: NonNegative<T>) -> Self {
+        Self(non_negative.0)
+    }
+}
+
+impl<T: NanType> From<Positive<T>> for NonNan<T> {
+    fn from(positive: Positive<T>) -> Self {
+        Self(positive.0)
+    }
+}
+
+impl<T: NanType> From<Positive<T>> for NonNegative<T> {
+    fn from(positive: Positive<T>) -> Self {
+        Self(positive.0)
+    }
+}
+
+/// A type that wraps a `NanType` and orders it according to the IEEE 754 `totalOrder` predicate
+/// (see [`f64::total_cmp`]), which makes it [`Ord`], [`Eq`] and [`Hash`] without rejecting any
+/// values. Where [`NonNan`] refuses `NaN`, this type puts it in a deterministic place instead, which
+/// is useful for sorting or keying data that may legitimately contain `NaN`.
+///
+/// Note that this ordering differs from the usual float comparisons in a few ways: `-0.0` is less
+/// than `0.0`, positive `NaN`s are greater than every other value, negative `NaN`s are less than
+/// every other value, and `NaN`s are equal to themselves.
+///
+/// With the `serde` feature enabled, this type (de)serializes as its contained value.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::non_nan::TotallyOrdered;
+/// let mut values: Vec<_> = vec![2.0, f64::NAN, -0.0, 0.0, f64::NEG_INFINITY]
+///     .into_iter()
+///     .map(TotallyOrdered::new)
+///     .collect();
+/// values.sort();
+///
+/// let values: Vec<_> = values.into_iter().map(TotallyOrdered::into_inner).collect();
+/// assert_eq!(values[..4], [f64::NEG_INFINITY, -0.0, 0.0, 2.0]);
+/// assert!(values[1].is_sign_negative());
+/// assert!(values[4].is_nan());
+/// ```
+#[derive(Shrinkwrap, Copy, Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct TotallyOrdered<T: NanType>(T);
+
+impl<T: NanType> TotallyOrdered<T> {
+    /// Creates a new `TotallyOrdered<T>`.
+    pub fn new(val: T) -> Self {
+        Self(val)
+    }
+
+    /// Consumes the `TotallyOrdered<T>`, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: NanType> From<T> for TotallyOrdered<T> {
     fn from(t: T) -> Self {
-        Self::new(t)
+        Self(t)
+    }
+}
+
+impl<T: NanType> PartialEq for TotallyOrdered<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: NanType> Eq for TotallyOrdered<T> {}
+
+impl<T: NanType> PartialOrd for TotallyOrdered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: NanType> Ord for TotallyOrdered<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T: NanType> Hash for TotallyOrdered<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_bits(state);
+    }
+}
+
+macro_rules! total_order {
+    ($($wrapper:ident)+) => {
+        $(
+            impl<T: NanType> Eq for $wrapper<T> {}
+
+            #[allow(clippy::derive_ord_xor_partial_ord)]
+            impl<T: NanType> Ord for $wrapper<T> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.partial_cmp(other).unwrap()
+                }
+            }
+        )+
+    };
+}
+
+total_order!(NonNan Finite NonNegative Positive);
+
+macro_rules! try_from_float {
+    ($wrapper:ident $error:ident; $($ty:ty)+) => {
+        $(
+            impl TryFrom<$ty> for $wrapper<$ty> {
+                type Error = $error;
+
+                fn try_from(t: $ty) -> Result<Self, Self::Error> {
+                    Self::try_new(t)
+                }
+            }
+        )+
+    };
+}
+
+try_from_float!(NonNan NanError; f32 f64);
+try_from_float!(Finite NonFiniteError; f32 f64);
+try_from_float!(NonNegative NegativeError; f32 f64);
+try_from_float!(Positive NonPositiveError; f32 f64);
+
+// Every binary operation can produce an invalid value even from valid operands (e.g. `∞ - ∞`,
+// `0 / 0` or `MAX * 2`), so they all re-check the result. The assign variants can't report
+// failure, so they panic instead.
+macro_rules! checked_binary_ops {
+    ($wrapper:ident $error:ident $panics:literal) => {
+        checked_binary_ops! {
+            $wrapper $error $panics;
+            Add add AddAssign add_assign;
+            Sub sub SubAssign sub_assign;
+            Mul mul MulAssign mul_assign;
+            Div div DivAssign div_assign;
+        }
+
+        impl<T: NanType + Neg<Output = T>> Neg for $wrapper<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+    };
+    (
+        $wrapper:ident $error:ident $panics:literal;
+        $($op:ident $op_fn:ident $op_assign:ident $op_assign_fn:ident;)+
+    ) => {
+        $(
+            impl<T: NanType + $op<Output = T>> $op for $wrapper<T> {
+                type Output = Result<Self, $error>;
+
+                fn $op_fn(self, rhs: Self) -> Self::Output {
+                    Self::try_new(self.0.$op_fn(rhs.0))
+                }
+            }
+
+            impl<T: NanType + $op<Output = T>> $op_assign for $wrapper<T> {
+                /// # Panics
+                ///
+                #[doc = $panics]
+                fn $op_assign_fn(&mut self, rhs: Self) {
+                    *self = Self::new(self.0.$op_fn(rhs.0));
+                }
+            }
+        )+
+    };
+}
+
+checked_binary_ops!(NonNan NanError "If the result is `NaN`.");
+checked_binary_ops!(Finite NonFiniteError "If the result is infinite or `NaN`.");
+checked_binary_ops! {
+    NonNegative NegativeError "If the result is negative or `NaN`.";
+    Sub sub SubAssign sub_assign;
+    Mul mul MulAssign mul_assign;
+    Div div DivAssign div_assign;
+}
+checked_binary_ops! {
+    Positive NonPositiveError "If the result is zero, negative or `NaN`.";
+    Sub sub SubAssign sub_assign;
+    Mul mul MulAssign mul_assign;
+    Div div DivAssign div_assign;
+}
+
+// The sum of two non-negative (or two positive) values can't leave that range, nor become `NaN`,
+// since neither operand can be negative infinity.
+macro_rules! closed_add {
+    ($($wrapper:ident)+) => {
+        $(
+            impl<T: NanType + Add<Output = T>> Add for $wrapper<T> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self::Output {
+                    Self(self.0 + rhs.0)
+                }
+            }
+
+            impl<T: NanType + Add<Output = T>> AddAssign for $wrapper<T> {
+                fn add_assign(&mut self, rhs: Self) {
+                    self.0 = self.0 + rhs.0;
+                }
+            }
+        )+
+    };
+}
+
+closed_add!(NonNegative Positive);
+
+#[cfg(feature = "serde")]
+macro_rules! validating_serde {
+    ($($wrapper:ident)+) => {
+        $(
+            #[cfg_attr(feature = "better-docs", doc(cfg(feature = "serde")))]
+            impl<T: NanType + Serialize> Serialize for $wrapper<T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            #[cfg_attr(feature = "better-docs", doc(cfg(feature = "serde")))]
+            impl<'de, T: NanType + Deserialize<'de>> Deserialize<'de> for $wrapper<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    Self::try_new(T::deserialize(deserializer)?).map_err(de::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "serde")]
+validating_serde!(NonNan Finite NonNegative Positive);
+
+/// Determines how [`FloatSliceExt`] deals with `NaN` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// `NaN` values are ignored, as if they weren't in the slice at all.
+    Skip,
+    /// Any `NaN` value in the slice causes the whole operation to fail with a [`NanError`].
+    Reject,
+}
+
+/// Provides `NaN`-aware minimum and maximum operations on slices of floats, returning the result
+/// as a [`NonNan`].
+pub trait FloatSliceExt<T: NanType> {
+    /// Returns the smallest value in the slice, or `None` if there are no (non-`NaN`) values.
+    ///
+    /// # Errors
+    ///
+    /// If `policy` is [`NanPolicy::Reject`] and the slice contains a `NaN`. Never fails with
+    /// [`NanPolicy::Skip`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::{FloatSliceExt, NanError, NanPolicy, NonNan};
+    /// let values = [3.0, f64::NAN, -1.5, 2.0];
+    /// assert_eq!(values.float_min(NanPolicy::Skip), Ok(Some(NonNan::new(-1.5))));
+    /// assert_eq!(values.float_min(NanPolicy::Reject), Err(NanError));
+    /// assert_eq!([f32::NAN].float_min(NanPolicy::Skip), Ok(None));
+    /// ```
+    fn float_min(&self, policy: NanPolicy) -> Result<Option<NonNan<T>>, NanError>;
+
+    /// Returns the largest value in the slice, or `None` if there are no (non-`NaN`) values.
+    ///
+    /// # Errors
+    ///
+    /// If `policy` is [`NanPolicy::Reject`] and the slice contains a `NaN`. Never fails with
+    /// [`NanPolicy::Skip`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::non_nan::{FloatSliceExt, NanError, NanPolicy, NonNan};
+    /// let values = [3.0, f64::NAN, -1.5, 2.0];
+    /// assert_eq!(values.float_max(NanPolicy::Skip), Ok(Some(NonNan::new(3.0))));
+    /// assert_eq!(values.float_max(NanPolicy::Reject), Err(NanError));
+    /// assert_eq!(<[f64]>::float_max(&[], NanPolicy::Reject), Ok(None));
+    /// ```
+    fn float_max(&self, policy: NanPolicy) -> Result<Option<NonNan<T>>, NanError>;
+}
+
+impl<T: NanType> FloatSliceExt<T> for [T] {
+    fn float_min(&self, policy: NanPolicy) -> Result<Option<NonNan<T>>, NanError> {
+        extreme_by(self, policy, |candidate, current| candidate < current)
+    }
+
+    fn float_max(&self, policy: NanPolicy) -> Result<Option<NonNan<T>>, NanError> {
+        extreme_by(self, policy, |candidate, current| candidate > current)
+    }
+}
+
+fn extreme_by<T: NanType>(
+    values: &[T],
+    policy: NanPolicy,
+    replaces: impl Fn(&NonNan<T>, &NonNan<T>) -> bool,
+) -> Result<Option<NonNan<T>>, NanError> {
+    let mut extreme: Option<NonNan<T>> = None;
+    for &value in values {
+        let value = match (NonNan::try_new(value), policy) {
+            (Ok(value), _) => value,
+            (Err(_), NanPolicy::Skip) => continue,
+            (Err(error), NanPolicy::Reject) => return Err(error),
+        };
+        if extreme.is_none_or(|current| replaces(&value, &current)) {
+            extreme = Some(value);
+        }
+    }
+    Ok(extreme)
+}
+
+/// The error returned when attempting to create a [`NonNan`] from a `NaN` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NanError;
+
+impl fmt::Display for NanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NaN values are not allowed")
+    }
+}
+
+impl Error for NanError {}
+
+/// The error returned when attempting to create a [`Finite`] from an infinite or `NaN` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonFiniteError;
+
+impl fmt::Display for NonFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("infinite and NaN values are not allowed")
+    }
+}
+
+impl Error for NonFiniteError {}
+
+/// The error returned when attempting to create a [`NonNegative`] from a negative or `NaN` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NegativeError;
+
+impl fmt::Display for NegativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("negative and NaN values are not allowed")
+    }
+}
+
+impl Error for NegativeError {}
+
+/// The error returned when attempting to create a [`Positive`] from a zero, negative or `NaN`
+/// value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonPositiveError;
+
+impl fmt::Display for NonPositiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("zero, negative and NaN values are not allowed")
+    }
+}
+
+impl Error for NonPositiveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_min_and_max_prefer_the_first_of_equal_values() {
+        let values = [0.0, -0.0, f64::NAN, 0.0];
+        let min = values.float_min(NanPolicy::Skip).unwrap().unwrap();
+        let max = values.float_max(NanPolicy::Skip).unwrap().unwrap();
+        assert!(min.is_sign_positive());
+        assert!(max.is_sign_positive());
+    }
+
+    #[test]
+    fn totally_ordered_keys_hash_consistently() {
+        let set: std::collections::HashSet<_> = [f64::NAN, f64::NAN, 0.0, -0.0, 1.0]
+            .iter()
+            .copied()
+            .map(TotallyOrdered::new)
+            .collect();
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(&TotallyOrdered::new(f64::NAN)));
+        assert!(set.contains(&TotallyOrdered::new(-0.0)));
+    }
+
+    #[test]
+    fn sign_refinements_handle_edge_values() {
+        assert!(NonNegative::try_new(-0.0_f64).is_ok());
+        assert_eq!(Positive::try_new(-0.0_f64), Err(NonPositiveError));
+        assert_eq!(NonNegative::try_new(f32::NAN), Err(NegativeError));
+        assert_eq!(Positive::try_new(f32::NAN), Err(NonPositiveError));
+
+        let infinity = NonNegative::new(f64::INFINITY);
+        assert_eq!(infinity + infinity, infinity);
+        assert_eq!(NonNegative::new(0.0) * infinity, Err(NegativeError));
+        assert_eq!(infinity - infinity, Err(NegativeError));
+    }
+
+    #[test]
+    fn float_min_and_max_handle_infinities() {
+        let values = [f32::INFINITY, 1.0, f32::NEG_INFINITY];
+        assert_eq!(
+            values.float_min(NanPolicy::Reject),
+            Ok(Some(NonNan::new(f32::NEG_INFINITY)))
+        );
+        assert_eq!(
+            values.float_max(NanPolicy::Reject),
+            Ok(Some(NonNan::new(f32::INFINITY)))
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use serde::de::value::{self, F64Deserializer};
+    use serde::de::IntoDeserializer;
+
+    #[test]
+    fn rejects_invalid_values() {
+        let nan: F64Deserializer<value::Error> = f64::NAN.into_deserializer();
+        let nan_error = NonNan::<f64>::deserialize(nan).unwrap_err();
+        assert_eq!(nan_error.to_string(), "NaN values are not allowed");
+
+        let infinity: F64Deserializer<value::Error> = f64::INFINITY.into_deserializer();
+        assert!(NonNan::<f64>::deserialize(infinity).is_ok());
+        let infinity_error = Finite::<f64>::deserialize(infinity).unwrap_err();
+        assert_eq!(
+            infinity_error.to_string(),
+            "infinite and NaN values are not allowed"
+        );
     }
 }
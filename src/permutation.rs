@@ -1,27 +1,23 @@
 //! Functionality for generating permutations
 
+use std::iter::FusedIterator;
 use std::ops::{Add, Mul};
 
 use num_traits::{NumCast, ToPrimitive, Zero};
 
-fn _heap_permutation<
-    T: Copy + ToPrimitive,
-    R: Mul<R, Output = R> + Add<R, Output = R> + Zero + NumCast,
->(
+fn _heap_permutation<T, R, F: FnMut(&[T]) -> R>(
     digits: &mut [T],
     remaining_operations: usize,
-    digit_length: usize,
+    fold: &mut F,
     result: &mut Vec<R>,
 ) {
     if remaining_operations == 1 {
-        result.push(digits.iter().fold(R::zero(), |sum, d| {
-            sum * R::from(10).unwrap() + R::from(*d).unwrap()
-        }));
+        result.push(fold(digits));
         return;
     }
 
     for i in 0..remaining_operations {
-        _heap_permutation(digits, remaining_operations - 1, digit_length, result);
+        _heap_permutation(digits, remaining_operations - 1, fold, result);
 
         if remaining_operations % 2 == 1 {
             digits.swap(0, remaining_operations - 1);
@@ -31,24 +27,356 @@ fn _heap_permutation<
     }
 }
 
-/// Creates every permutation of all digits in `digits`. Shuffles `digits` during use; no guarantees
-/// are made about its elements' order upon completion.
+/// Creates every permutation of all digits in `digits`, interpreting each permutation as a base 10
+/// number. Shuffles `digits` during use; no guarantees are made about its elements' order upon
+/// completion.
+///
+/// # Panics
+///
+/// If any digit isn't a whole number in the range `0..10`, or if a permutation can't be
+/// represented by `R`.
 pub fn heap_permutation<
     T: Copy + ToPrimitive,
-    R: Mul<R, Output = R> + Add<R, Output = R> + Zero + NumCast,
+    R: Mul<R, Output = R> + Add<R, Output = R> + Zero + NumCast + Clone,
 >(
     digits: &mut [T],
 ) -> Vec<R> {
+    heap_permutation_with_radix(digits, 10)
+}
+
+/// Creates every permutation of all digits in `digits`, interpreting each permutation as a number
+/// in the given `radix`. Shuffles `digits` during use; no guarantees are made about its elements'
+/// order upon completion.
+///
+/// # Panics
+///
+/// If `radix` is less than 2, if any digit isn't a whole number in the range `0..radix`, or if a
+/// permutation can't be represented by `R`.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::permutation::heap_permutation_with_radix;
+/// let mut digits = [0xa, 0xb];
+/// let numbers: Vec<u32> = heap_permutation_with_radix(&mut digits, 16);
+/// assert_eq!(numbers, [0xab, 0xba]);
+/// ```
+pub fn heap_permutation_with_radix<
+    T: Copy + ToPrimitive,
+    R: Mul<R, Output = R> + Add<R, Output = R> + Zero + NumCast + Clone,
+>(
+    digits: &mut [T],
+    radix: u32,
+) -> Vec<R> {
+    assert!(radix >= 2, "The radix must be at least 2.");
+    let limit: f64 = radix.into();
+    for digit in digits.iter() {
+        let is_valid = digit
+            .to_f64()
+            .is_some_and(|d| d >= 0.0 && d < limit && d.fract() == 0.0);
+        assert!(
+            is_valid,
+            "Every digit must be a whole number less than the radix."
+        );
+    }
+
+    let radix = R::from(radix).expect("The radix must be representable by the result type.");
+    heap_permutation_with(digits, |permutation| {
+        permutation.iter().fold(R::zero(), |sum, d| {
+            let digit = R::from(*d).expect("The digit must be representable by the result type.");
+            sum * radix.clone() + digit
+        })
+    })
+}
+
+/// Creates every permutation of all elements in `digits`, turning each permutation into a result
+/// with `fold`. Shuffles `digits` during use; no guarantees are made about its elements' order upon
+/// completion.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::permutation::heap_permutation_with;
+/// let mut letters = ['a', 'b', 'c'];
+/// let words = heap_permutation_with(&mut letters, |letters| letters.iter().collect::<String>());
+/// assert_eq!(words, ["abc", "bac", "cab", "acb", "bca", "cba"]);
+/// ```
+pub fn heap_permutation_with<T, R, F: FnMut(&[T]) -> R>(digits: &mut [T], mut fold: F) -> Vec<R> {
     let digits_len = digits.len();
     let mut result = vec![];
-    _heap_permutation(digits, digits_len, digits_len, &mut result);
+    _heap_permutation(digits, digits_len, &mut fold, &mut result);
 
     result
 }
 
+/// Creates an iterator over every permutation of `items`, generated lazily using Heap's algorithm.
+/// Unlike [`heap_permutation`], which builds all `n!` results up front, this yields each
+/// arrangement on demand, so callers can stream, filter and stop early without allocating every
+/// permutation.
+///
+/// The first permutation yielded is `items` in its original order, and every subsequent one differs
+/// from the previous by a single swap. An empty input yields a single, empty permutation.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::permutation::permutations;
+/// let arrangements: Vec<_> = permutations(vec!['a', 'b', 'c']).collect();
+/// assert_eq!(
+///     arrangements,
+///     [
+///         ['a', 'b', 'c'],
+///         ['b', 'a', 'c'],
+///         ['c', 'a', 'b'],
+///         ['a', 'c', 'b'],
+///         ['b', 'c', 'a'],
+///         ['c', 'b', 'a'],
+///     ]
+/// );
+///
+/// // Only generates permutations until the first match, out of the 10! possible ones
+/// let first_descending = permutations(0..10).find(|p| p[0] == 9 && p[1] == 8);
+/// assert!(first_descending.is_some());
+/// ```
+pub fn permutations<T: Clone, I: IntoIterator<Item = T>>(items: I) -> Permutations<T> {
+    let items: Vec<T> = items.into_iter().collect();
+    Permutations {
+        counters: vec![0; items.len()],
+        items,
+        index: 1,
+        started: false,
+    }
+}
+
+/// An iterator over every permutation of a list of items.
+///
+/// This `struct` is created by the [`permutations`] function. See its documentation for more.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Permutations<T> {
+    items: Vec<T>,
+    // The state of the otherwise recursive Heap's algorithm; `counters[i]` is how many of the
+    // iterations at recursion level `i` have been completed.
+    counters: Vec<usize>,
+    index: usize,
+    started: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+
+        while self.index < self.items.len() {
+            let index = self.index;
+            if self.counters[index] < index {
+                if index.is_multiple_of(2) {
+                    self.items.swap(0, index);
+                } else {
+                    self.items.swap(self.counters[index], index);
+                }
+                self.counters[index] += 1;
+                self.index = 1;
+                return Some(self.items.clone());
+            }
+
+            self.counters[index] = 0;
+            self.index += 1;
+        }
+
+        None
+    }
+}
+
+impl<T: Clone> FusedIterator for Permutations<T> {}
+
+/// A rearrangement of the indices `0..len`, e.g. the order that sorts a slice, that can be applied to
+/// any slice of the same length. This makes it useful for applying one sort order to several
+/// parallel arrays.
+///
+/// A permutation is stored as the list of source indices: applying it moves the element at
+/// `indices[i]` to position `i`.
+///
+/// # Examples
+/// ```rust
+/// # use ilyvion_util::permutation::Permutation;
+/// let mut names = vec!["carol", "alice", "bob"];
+/// let mut ages = vec![35, 30, 25];
+///
+/// let by_name = Permutation::sorting_of(&names);
+/// by_name.apply_to(&mut names);
+/// by_name.apply_to(&mut ages);
+/// assert_eq!(names, ["alice", "bob", "carol"]);
+/// assert_eq!(ages, [30, 25, 35]);
+///
+/// // The inverse undoes the sort
+/// by_name.inverse().apply_to(&mut ages);
+/// assert_eq!(ages, [35, 30, 25]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// Creates the permutation of `len` elements that leaves every element in place.
+    #[must_use]
+    pub fn identity(len: usize) -> Self {
+        Self {
+            indices: (0..len).collect(),
+        }
+    }
+
+    /// Creates a permutation from a list of source indices, where the element at `indices[i]` is
+    /// moved to position `i`. Returns `None` unless `indices` contains every index in
+    /// `0..indices.len()` exactly once.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::permutation::Permutation;
+    /// let mut letters = ['a', 'b', 'c'];
+    /// Permutation::from_indices(vec![2, 0, 1]).unwrap().apply_to(&mut letters);
+    /// assert_eq!(letters, ['c', 'a', 'b']);
+    ///
+    /// assert!(Permutation::from_indices(vec![0, 0, 1]).is_none());
+    /// assert!(Permutation::from_indices(vec![0, 3]).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_indices(indices: Vec<usize>) -> Option<Self> {
+        let mut seen = vec![false; indices.len()];
+        for &index in &indices {
+            if index >= seen.len() || seen[index] {
+                return None;
+            }
+            seen[index] = true;
+        }
+        Some(Self { indices })
+    }
+
+    /// Creates the permutation that sorts `slice`. The sort is stable, so equal elements keep their
+    /// relative order.
+    #[must_use]
+    pub fn sorting_of<T: Ord>(slice: &[T]) -> Self {
+        let mut indices: Vec<usize> = (0..slice.len()).collect();
+        indices.sort_by(|&a, &b| slice[a].cmp(&slice[b]));
+        Self { indices }
+    }
+
+    /// Creates the permutation that sorts `slice` by the key extracted by `key`. The sort is
+    /// stable, so elements with equal keys keep their relative order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::permutation::Permutation;
+    /// let words = ["ccc", "a", "bb"];
+    /// let by_length = Permutation::sorting_by_key(&words, |word| word.len());
+    /// assert_eq!(by_length.as_slice(), [1, 2, 0]);
+    /// ```
+    pub fn sorting_by_key<T, K: Ord>(slice: &[T], mut key: impl FnMut(&T) -> K) -> Self {
+        let mut indices: Vec<usize> = (0..slice.len()).collect();
+        indices.sort_by_key(|&index| key(&slice[index]));
+        Self { indices }
+    }
+
+    /// Returns the number of elements this permutation rearranges.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if this permutation rearranges zero elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the source indices of this permutation; see [`Permutation::from_indices`].
+    #[must_use]
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Rearranges `slice` in place according to this permutation.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `slice` differs from the length of the permutation.
+    pub fn apply_to<T>(&self, slice: &mut [T]) {
+        assert_eq!(
+            slice.len(),
+            self.len(),
+            "The slice must have the same length as the permutation."
+        );
+
+        // Every cycle of the permutation is resolved by swapping its elements into place one by
+        // one, so no element needs to be cloned.
+        let mut placed = vec![false; self.len()];
+        for start in 0..self.len() {
+            let mut current = start;
+            while !placed[current] {
+                placed[current] = true;
+                let source = self.indices[current];
+                if source == start {
+                    break;
+                }
+                slice.swap(current, source);
+                current = source;
+            }
+        }
+    }
+
+    /// Returns the permutation that undoes this one.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let mut indices = vec![0; self.len()];
+        for (position, &source) in self.indices.iter().enumerate() {
+            indices[source] = position;
+        }
+        Self { indices }
+    }
+
+    /// Returns the permutation that has the same effect as applying `self` followed by `next`.
+    ///
+    /// # Panics
+    ///
+    /// If the permutations have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ilyvion_util::permutation::Permutation;
+    /// let reverse = Permutation::from_indices(vec![2, 1, 0]).unwrap();
+    /// let rotate = Permutation::from_indices(vec![1, 2, 0]).unwrap();
+    ///
+    /// let mut stepwise = ['a', 'b', 'c'];
+    /// reverse.apply_to(&mut stepwise);
+    /// rotate.apply_to(&mut stepwise);
+    ///
+    /// let mut composed = ['a', 'b', 'c'];
+    /// reverse.then(&rotate).apply_to(&mut composed);
+    /// assert_eq!(composed, stepwise);
+    /// ```
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        assert_eq!(
+            self.len(),
+            next.len(),
+            "The permutations must have the same length."
+        );
+        Self {
+            indices: next
+                .indices
+                .iter()
+                .map(|&index| self.indices[index])
+                .collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::heap_permutation;
+    use super::{heap_permutation, heap_permutation_with_radix, permutations, Permutation};
+    use std::collections::HashSet;
 
     #[test]
     fn two() {
@@ -63,4 +391,78 @@ mod tests {
         let sut = heap_permutation(&mut digits);
         assert_eq!(vec![123, 213, 312, 132, 231, 321], sut);
     }
+
+    #[test]
+    fn radix_two() {
+        let mut digits = [1_u8, 0, 0];
+        let mut sut: Vec<u8> = heap_permutation_with_radix(&mut digits, 2);
+        sut.sort_unstable();
+        assert_eq!(sut, [0b001, 0b001, 0b010, 0b010, 0b100, 0b100]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Every digit must be a whole number less than the radix.")]
+    fn rejects_digits_outside_the_radix() {
+        let mut digits = [1, 10];
+        let _: Vec<u64> = heap_permutation(&mut digits);
+    }
+
+    #[test]
+    #[should_panic(expected = "Every digit must be a whole number less than the radix.")]
+    fn rejects_fractional_digits() {
+        let mut digits = [1.5, 2.0];
+        let _: Vec<f64> = heap_permutation(&mut digits);
+    }
+
+    #[test]
+    fn permutations_yields_every_arrangement_once() {
+        for n in 0..7 {
+            let all: Vec<_> = permutations(0..n).collect();
+            let unique: HashSet<_> = all.iter().cloned().collect();
+            let factorial: usize = (1..=n).product();
+            assert_eq!(all.len(), factorial);
+            assert_eq!(unique.len(), factorial);
+        }
+    }
+
+    #[test]
+    fn permutations_is_fused() {
+        let mut iter = permutations(vec![1]);
+        assert_eq!(iter.next(), Some(vec![1]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn permutation_apply_to_matches_its_indices() {
+        for indices in permutations(0..6) {
+            let permutation = Permutation::from_indices(indices.clone()).unwrap();
+            let mut values: Vec<_> = (0..6).map(|value| value * 10).collect();
+            permutation.apply_to(&mut values);
+            let expected: Vec<_> = indices.iter().map(|index| index * 10).collect();
+            assert_eq!(values, expected);
+
+            assert_eq!(
+                permutation.then(&permutation.inverse()),
+                Permutation::identity(6)
+            );
+            assert_eq!(
+                permutation.inverse().then(&permutation),
+                Permutation::identity(6)
+            );
+        }
+    }
+
+    #[test]
+    fn permutation_sorting_of_is_stable() {
+        let keys = [3, 1, 3, 1, 2];
+        let permutation = Permutation::sorting_of(&keys);
+        assert_eq!(permutation.as_slice(), [1, 3, 4, 0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The slice must have the same length as the permutation.")]
+    fn permutation_apply_to_rejects_other_lengths() {
+        Permutation::identity(2).apply_to(&mut [1, 2, 3]);
+    }
 }
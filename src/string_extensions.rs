@@ -1,6 +1,59 @@
 //! Various [`String`] and [`str`] extensions
 
 use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::iter;
+use std::ops::{Bound, RangeBounds};
+#[cfg(feature = "unicode")]
+use unicode_normalization::UnicodeNormalization;
+#[cfg(feature = "unicode")]
+use unicode_segmentation::{Graphemes, UnicodeSegmentation, UnicodeWords};
+#[cfg(feature = "unicode")]
+use unicode_width::UnicodeWidthStr;
+
+mod escape;
+mod split_keep;
+mod wrap;
+
+pub use split_keep::{SplitKeep, SplitPart, SplitPattern};
+pub use wrap::Wrap;
+
+/// The error type returned by [`StrExtensions::substring_chars`] when the range is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharRangeError {
+    /// The range starts after it ends.
+    StartAfterEnd {
+        /// The character index the range starts at
+        start: usize,
+        /// The character index the range ends at
+        end: usize,
+    },
+    /// The range extends beyond the end of the [`str`].
+    OutOfBounds {
+        /// The character index beyond the end of the [`str`]
+        index: usize,
+        /// The length of the [`str`] in characters
+        len: usize,
+    },
+}
+
+impl fmt::Display for CharRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StartAfterEnd { start, end } => {
+                write!(f, "range starts at {} but ends at {}", start, end)
+            }
+            Self::OutOfBounds { index, len } => write!(
+                f,
+                "character index {} out of bounds for length {}",
+                index, len
+            ),
+        }
+    }
+}
+
+impl Error for CharRangeError {}
 
 /// The trait responsible for adding methods to [`str`].
 pub trait StrExtensions {
@@ -8,8 +61,324 @@ pub trait StrExtensions {
     /// already is ASCII and lowercase.
     fn to_ascii_lowercase_cow(&self) -> Cow<'_, str>;
 
+    /// Works like [`str::to_ascii_uppercase`], but returns a [`Cow::Borrowed`] when the original
+    /// already is ASCII and uppercase.
+    fn to_ascii_uppercase_cow(&self) -> Cow<'_, str>;
+
+    /// Works like [`str::to_lowercase`], but returns a [`Cow::Borrowed`] when lowercasing
+    /// wouldn't change the original.
+    fn to_lowercase_cow(&self) -> Cow<'_, str>;
+
+    /// Works like [`str::to_uppercase`], but returns a [`Cow::Borrowed`] when uppercasing
+    /// wouldn't change the original.
+    fn to_uppercase_cow(&self) -> Cow<'_, str>;
+
     /// Returns whether or not this [`str`] is already ASCII lowercase.
     fn is_ascii_lowercase(&self) -> bool;
+
+    /// Returns whether or not this [`str`] is already ASCII uppercase.
+    fn is_ascii_uppercase(&self) -> bool;
+
+    /// Wraps this [`str`] at word boundaries into lines that are at most `width` characters wide
+    /// (or columns wide, with the `unicode` feature; see [`Wrap`]).
+    /// The returned [`Wrap`] can be configured to break words that don't fit on a line and to
+    /// ignore the existing line breaks, and then be displayed or turned into a list of lines.
+    ///
+    /// # Panics
+    ///
+    /// If `width` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// let text = "The quick brown fox jumps over the lazy dog";
+    /// assert_eq!(
+    ///     text.wrap(16).to_string(),
+    ///     "The quick brown\nfox jumps over\nthe lazy dog"
+    /// );
+    /// assert_eq!(
+    ///     "Supercalifragilistic".wrap(8).break_long_words(true).lines(),
+    ///     ["Supercal", "ifragili", "stic"]
+    /// );
+    /// ```
+    fn wrap(&self, width: usize) -> Wrap<'_>;
+
+    /// Puts `prefix` in front of every line of this [`str`] that isn't blank, i.e., that contains
+    /// more than just whitespace. Blank lines are left alone so as to not add trailing whitespace.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("one\n\ntwo\n".indent("  "), "  one\n\n  two\n");
+    /// ```
+    fn indent(&self, prefix: &str) -> String;
+
+    /// Removes the leading whitespace all non-blank lines of this [`str`] have in common, which
+    /// makes it convenient to indent multi-line string literals along with the surrounding code.
+    /// Lines consisting only of whitespace are emptied. Returns a [`Cow::Borrowed`] when there
+    /// was nothing to remove.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// let template = "
+    ///     fn main() {
+    ///         println!();
+    ///     }
+    /// ";
+    /// assert_eq!(
+    ///     template.dedent(),
+    ///     "\nfn main() {\n    println!();\n}\n"
+    /// );
+    /// ```
+    fn dedent(&self) -> Cow<'_, str>;
+
+    /// Splits this [`str`] on `pattern` like [`str::split`], but also yields the matches of the
+    /// pattern, telling the two apart with [`SplitPart`]. Unlike [`str::split`], no empty
+    /// [`SplitPart::Text`]s are yielded between adjacent matches or at either end.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::{SplitPart, StrExtensions};
+    ///
+    /// let parts: Vec<_> = "1+22*3".split_keep(|c: char| !c.is_ascii_digit()).collect();
+    /// assert_eq!(
+    ///     parts,
+    ///     [
+    ///         SplitPart::Text("1"),
+    ///         SplitPart::Delimiter("+"),
+    ///         SplitPart::Text("22"),
+    ///         SplitPart::Delimiter("*"),
+    ///         SplitPart::Text("3"),
+    ///     ]
+    /// );
+    /// ```
+    fn split_keep<P: SplitPattern>(&self, pattern: P) -> SplitKeep<'_, P>;
+
+    /// Replaces every occurrence of each `(from, to)` pair in `replacements` in a single pass, so
+    /// that replaced text is never replaced again. Where several patterns match, the left-most
+    /// one wins, and of those starting at the same place, the longest. Empty patterns are
+    /// ignored. Returns a [`Cow::Borrowed`] when nothing was replaced.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// let escaped = "a < b && b > c".replace_all(&[("&", "&amp;"), ("<", "&lt;"), (">", "&gt;")]);
+    /// assert_eq!(escaped, "a &lt; b &amp;&amp; b &gt; c");
+    ///
+    /// // Swapping two words only works when done in a single pass
+    /// assert_eq!("cat dog".replace_all(&[("cat", "dog"), ("dog", "cat")]), "dog cat");
+    /// ```
+    fn replace_all(&self, replacements: &[(&str, &str)]) -> Cow<'_, str>;
+
+    /// Returns whether or not this [`str`] is empty or consists only of whitespace.
+    fn is_blank(&self) -> bool;
+
+    /// Collapses every run of whitespace in this [`str`] into a single space and removes any
+    /// leading and trailing whitespace. Returns a [`Cow::Borrowed`] when the original already is
+    /// normalized that way.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("  hello, \t\n world ".normalize_whitespace(), "hello, world");
+    /// ```
+    fn normalize_whitespace(&self) -> Cow<'_, str>;
+
+    /// Converts this [`str`] to Unicode Normalization Form C (canonical composition), returning a
+    /// [`Cow::Borrowed`] when the original already is in that form.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    /// use std::borrow::Cow;
+    ///
+    /// assert_eq!("e\u{301}".nfc_cow(), "\u{e9}");
+    /// assert!(matches!("\u{e9}".nfc_cow(), Cow::Borrowed(_)));
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "unicode")))]
+    fn nfc_cow(&self) -> Cow<'_, str>;
+
+    /// Converts this [`str`] to Unicode Normalization Form KC (compatibility composition),
+    /// returning a [`Cow::Borrowed`] when the original already is in that form.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("\u{fb01}ne".nfkc_cow(), "fine");
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "unicode")))]
+    fn nfkc_cow(&self) -> Cow<'_, str>;
+
+    /// Returns an iterator over the extended grapheme clusters of this [`str`], i.e., over what a
+    /// reader would consider to be the individual characters.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// let graphemes: Vec<_> = "e\u{301}🇳🇴!".graphemes_ext().collect();
+    /// assert_eq!(graphemes, ["e\u{301}", "🇳🇴", "!"]);
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "unicode")))]
+    fn graphemes_ext(&self) -> Graphemes<'_>;
+
+    /// Returns an iterator over the words of this [`str`], as determined by the Unicode word
+    /// boundary rules. Punctuation and whitespace between the words is left out.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// let words: Vec<_> = "Don't panic, it's 3.14!".words_ext().collect();
+    /// assert_eq!(words, ["Don't", "panic", "it's", "3.14"]);
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "unicode")))]
+    fn words_ext(&self) -> UnicodeWords<'_>;
+
+    /// Returns the number of columns this [`str`] takes up when shown in a terminal, where wide
+    /// characters like CJK ideographs and most emoji take up two columns and combining characters
+    /// take up none.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("abc".display_width(), 3);
+    /// assert_eq!("日本語".display_width(), 6);
+    /// assert_eq!("e\u{301}".display_width(), 1);
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "unicode")))]
+    fn display_width(&self) -> usize;
+
+    /// Returns the substring covering `range`, where the range is given in characters rather than
+    /// in bytes, so that it can't end up in the middle of a multi-byte character.
+    ///
+    /// # Errors
+    ///
+    /// If the range starts after it ends, or extends beyond the end of this [`str`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("blåbærsyltetøy".substring_chars(2..7), Ok("åbærs"));
+    /// assert_eq!("blåbærsyltetøy".substring_chars(11..), Ok("tøy"));
+    /// assert!("blåbær".substring_chars(..7).is_err());
+    /// ```
+    fn substring_chars<R: RangeBounds<usize>>(&self, range: R) -> Result<&str, CharRangeError>;
+
+    /// Escapes the characters that have a special meaning in HTML (`&`, `<`, `>`, `"` and `'`),
+    /// making this [`str`] safe to use both as text and as an attribute value. Returns a
+    /// [`Cow::Borrowed`] when there was nothing to escape.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("<b>Tom & Jerry</b>".escape_html(), "&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;");
+    /// ```
+    fn escape_html(&self) -> Cow<'_, str>;
+
+    /// Replaces HTML character references, i.e., the ones produced by [`escape_html`] as well as
+    /// `&apos;`, `&nbsp;` and numeric references like `&#39;` or `&#x27;`, with the characters
+    /// they represent. Anything that isn't a recognized reference is left alone. Returns a
+    /// [`Cow::Borrowed`] when there was nothing to unescape.
+    ///
+    /// [`escape_html`]: StrExtensions::escape_html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("Tom &amp; Jerry&#x27;s".unescape_html(), "Tom & Jerry's");
+    /// ```
+    fn unescape_html(&self) -> Cow<'_, str>;
+
+    /// Quotes this [`str`] so that a POSIX shell treats it as a single word with no special
+    /// characters. Returns a [`Cow::Borrowed`] when no quoting is needed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// assert_eq!("notes.txt".escape_shell_arg(), "notes.txt");
+    /// assert_eq!("Bob's notes.txt".escape_shell_arg(), r"'Bob'\''s notes.txt'");
+    /// ```
+    fn escape_shell_arg(&self) -> Cow<'_, str>;
+
+    /// Removes the quoting from a single POSIX shell word, handling single quotes, double quotes
+    /// and backslash escapes the way a shell would. Unquoted whitespace is kept rather than
+    /// splitting the word. Returns a [`Cow::Borrowed`] when there was nothing to unquote, or
+    /// [`None`] if a quote is left open or the word ends in a lone backslash.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use ilyvion_util::string_extensions::StrExtensions;
+    ///
+    /// let unescaped = r"'Bob'\''s notes.txt'".unescape_shell_arg();
+    /// assert_eq!(unescaped.as_deref(), Some("Bob's notes.txt"));
+    /// ```
+    fn unescape_shell_arg(&self) -> Option<Cow<'_, str>>;
 }
 
 impl StrExtensions for str {
@@ -21,6 +390,30 @@ impl StrExtensions for str {
         }
     }
 
+    fn to_ascii_uppercase_cow(&self) -> Cow<'_, str> {
+        if self.is_ascii_uppercase() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.to_ascii_uppercase())
+        }
+    }
+
+    fn to_lowercase_cow(&self) -> Cow<'_, str> {
+        if self.chars().all(|c| c.to_lowercase().eq(Some(c))) {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.to_lowercase())
+        }
+    }
+
+    fn to_uppercase_cow(&self) -> Cow<'_, str> {
+        if self.chars().all(|c| c.to_uppercase().eq(Some(c))) {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.to_uppercase())
+        }
+    }
+
     fn is_ascii_lowercase(&self) -> bool {
         let bytes = self.as_bytes();
         bytes.is_ascii()
@@ -28,4 +421,394 @@ impl StrExtensions for str {
                 .iter()
                 .all(|b| !b.is_ascii_alphabetic() || b.is_ascii_lowercase())
     }
+
+    fn is_ascii_uppercase(&self) -> bool {
+        let bytes = self.as_bytes();
+        bytes.is_ascii()
+            && bytes
+                .iter()
+                .all(|b| !b.is_ascii_alphabetic() || b.is_ascii_uppercase())
+    }
+
+    fn wrap(&self, width: usize) -> Wrap<'_> {
+        Wrap::new(self, width)
+    }
+
+    fn indent(&self, prefix: &str) -> String {
+        let mut indented = String::with_capacity(self.len());
+        for line in self.split_inclusive('\n') {
+            if !line.trim().is_empty() {
+                indented.push_str(prefix);
+            }
+            indented.push_str(line);
+        }
+
+        indented
+    }
+
+    fn dedent(&self) -> Cow<'_, str> {
+        let mut margin: Option<&Self> = None;
+        let mut has_blank_whitespace = false;
+        for line in self.split_inclusive('\n') {
+            let content = line.trim_start();
+            if content.is_empty() {
+                has_blank_whitespace |= !line.trim_end_matches(&['\n', '\r'][..]).is_empty();
+                continue;
+            }
+
+            let leading = &line[..line.len() - content.len()];
+            margin = Some(margin.map_or(leading, |margin| {
+                let common = margin
+                    .char_indices()
+                    .zip(leading.chars())
+                    .find(|&((_, a), b)| a != b)
+                    .map_or_else(|| margin.len().min(leading.len()), |((i, _), _)| i);
+                &margin[..common]
+            }));
+        }
+
+        let margin = margin.unwrap_or("");
+        if margin.is_empty() && !has_blank_whitespace {
+            return Cow::Borrowed(self);
+        }
+
+        let mut dedented = String::with_capacity(self.len());
+        for line in self.split_inclusive('\n') {
+            if line.trim_start().is_empty() {
+                dedented.push_str(&line[line.trim_end_matches(&['\n', '\r'][..]).len()..]);
+            } else {
+                dedented.push_str(&line[margin.len()..]);
+            }
+        }
+
+        Cow::Owned(dedented)
+    }
+
+    fn split_keep<P: SplitPattern>(&self, pattern: P) -> SplitKeep<'_, P> {
+        SplitKeep::new(self, pattern)
+    }
+
+    fn replace_all(&self, replacements: &[(&str, &str)]) -> Cow<'_, str> {
+        let mut replaced = String::new();
+        // The start of the text that has yet to be copied into `replaced`
+        let mut copied = 0;
+        let mut i = 0;
+        while let Some(c) = self[i..].chars().next() {
+            let longest = replacements
+                .iter()
+                .filter(|(from, _)| !from.is_empty() && self[i..].starts_with(from))
+                .max_by_key(|(from, _)| from.len());
+            if let Some((from, to)) = longest {
+                replaced.push_str(&self[copied..i]);
+                replaced.push_str(to);
+                i += from.len();
+                copied = i;
+            } else {
+                i += c.len_utf8();
+            }
+        }
+
+        if copied == 0 {
+            Cow::Borrowed(self)
+        } else {
+            replaced.push_str(&self[copied..]);
+            Cow::Owned(replaced)
+        }
+    }
+
+    fn is_blank(&self) -> bool {
+        self.chars().all(char::is_whitespace)
+    }
+
+    fn normalize_whitespace(&self) -> Cow<'_, str> {
+        let is_normalized = self.trim().len() == self.len()
+            && !self.contains(|c: char| c.is_whitespace() && c != ' ')
+            && !self.contains("  ");
+        if is_normalized {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    #[cfg(feature = "unicode")]
+    fn nfc_cow(&self) -> Cow<'_, str> {
+        if unicode_normalization::is_nfc(self) {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.nfc().collect())
+        }
+    }
+
+    #[cfg(feature = "unicode")]
+    fn nfkc_cow(&self) -> Cow<'_, str> {
+        if unicode_normalization::is_nfkc(self) {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.nfkc().collect())
+        }
+    }
+
+    #[cfg(feature = "unicode")]
+    fn graphemes_ext(&self) -> Graphemes<'_> {
+        self.graphemes(true)
+    }
+
+    #[cfg(feature = "unicode")]
+    fn words_ext(&self) -> UnicodeWords<'_> {
+        self.unicode_words()
+    }
+
+    #[cfg(feature = "unicode")]
+    fn display_width(&self) -> usize {
+        UnicodeWidthStr::width(self)
+    }
+
+    fn substring_chars<R: RangeBounds<usize>>(&self, range: R) -> Result<&str, CharRangeError> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(end.saturating_add(1)),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        if let Some(end) = end.filter(|&end| start > end) {
+            return Err(CharRangeError::StartAfterEnd { start, end });
+        }
+
+        let out_of_bounds = |index| CharRangeError::OutOfBounds {
+            index,
+            len: self.chars().count(),
+        };
+        let mut boundaries = self
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(iter::once(self.len()));
+        let start_byte = boundaries.nth(start).ok_or_else(|| out_of_bounds(start))?;
+        let end_byte = match end {
+            None => self.len(),
+            Some(end) if end == start => start_byte,
+            Some(end) => boundaries
+                .nth(end - start - 1)
+                .ok_or_else(|| out_of_bounds(end))?,
+        };
+
+        Ok(&self[start_byte..end_byte])
+    }
+
+    fn escape_html(&self) -> Cow<'_, str> {
+        escape::escape_html(self)
+    }
+
+    fn unescape_html(&self) -> Cow<'_, str> {
+        escape::unescape_html(self)
+    }
+
+    fn escape_shell_arg(&self) -> Cow<'_, str> {
+        escape::escape_shell_arg(self)
+    }
+
+    fn unescape_shell_arg(&self) -> Option<Cow<'_, str>> {
+        escape::unescape_shell_arg(self)
+    }
+}
+
+/// The trait responsible for adding methods to [`Cow<str>`](Cow).
+///
+/// The methods trim the [`str`] like their counterparts on [`str`], but keep a [`Cow::Borrowed`]
+/// borrowed and trim a [`Cow::Owned`] in place, so that neither ever allocates. This makes them
+/// convenient to chain after the `_cow` methods of [`StrExtensions`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use ilyvion_util::string_extensions::{CowStrExtensions, StrExtensions};
+/// use std::borrow::Cow;
+///
+/// let name = "  Ferris  ".to_ascii_lowercase_cow().trim_cow();
+/// assert_eq!(name, "ferris");
+///
+/// let path = Cow::Borrowed("src/lib.rs").trim_end_matches_cow(".rs");
+/// assert!(matches!(path, Cow::Borrowed("src/lib")));
+/// ```
+pub trait CowStrExtensions<'a> {
+    /// Works like [`str::trim`].
+    #[must_use]
+    fn trim_cow(self) -> Cow<'a, str>;
+
+    /// Works like [`str::trim_start`].
+    #[must_use]
+    fn trim_start_cow(self) -> Cow<'a, str>;
+
+    /// Works like [`str::trim_end`].
+    #[must_use]
+    fn trim_end_cow(self) -> Cow<'a, str>;
+
+    /// Works like [`str::trim_matches`].
+    #[must_use]
+    fn trim_matches_cow<P: SplitPattern>(self, pattern: P) -> Cow<'a, str>;
+
+    /// Works like [`str::trim_start_matches`].
+    #[must_use]
+    fn trim_start_matches_cow<P: SplitPattern>(self, pattern: P) -> Cow<'a, str>;
+
+    /// Works like [`str::trim_end_matches`].
+    #[must_use]
+    fn trim_end_matches_cow<P: SplitPattern>(self, pattern: P) -> Cow<'a, str>;
+}
+
+impl<'a> CowStrExtensions<'a> for Cow<'a, str> {
+    fn trim_cow(self) -> Self {
+        self.trim_start_cow().trim_end_cow()
+    }
+
+    fn trim_start_cow(self) -> Self {
+        let start = self.len() - self.trim_start().len();
+        keep_range(self, start, None)
+    }
+
+    fn trim_end_cow(self) -> Self {
+        let end = self.trim_end().len();
+        keep_range(self, 0, Some(end))
+    }
+
+    fn trim_matches_cow<P: SplitPattern>(self, mut pattern: P) -> Self {
+        let mut start = 0;
+        while let Some(len) = pattern.prefix_len(&self[start..]) {
+            start += len;
+        }
+        let mut end = self.len();
+        while let Some(len) = pattern.suffix_len(&self[start..end]) {
+            end -= len;
+        }
+        keep_range(self, start, Some(end))
+    }
+
+    fn trim_start_matches_cow<P: SplitPattern>(self, mut pattern: P) -> Self {
+        let mut start = 0;
+        while let Some(len) = pattern.prefix_len(&self[start..]) {
+            start += len;
+        }
+        keep_range(self, start, None)
+    }
+
+    fn trim_end_matches_cow<P: SplitPattern>(self, mut pattern: P) -> Self {
+        let mut end = self.len();
+        while let Some(len) = pattern.suffix_len(&self[..end]) {
+            end -= len;
+        }
+        keep_range(self, 0, Some(end))
+    }
+}
+
+/// Narrows `s` down to `start..end` without allocating.
+fn keep_range(s: Cow<'_, str>, start: usize, end: Option<usize>) -> Cow<'_, str> {
+    match s {
+        Cow::Borrowed(s) => Cow::Borrowed(&s[start..end.unwrap_or(s.len())]),
+        Cow::Owned(mut s) => {
+            if let Some(end) = end {
+                s.truncate(end);
+            }
+            s.drain(..start);
+            Cow::Owned(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cow_conversions_borrow_when_unchanged() {
+        assert!(matches!("ABC-1".to_ascii_uppercase_cow(), Cow::Borrowed(_)));
+        assert_eq!("aBc".to_ascii_uppercase_cow(), "ABC");
+
+        assert!(matches!("straße".to_lowercase_cow(), Cow::Borrowed(_)));
+        assert_eq!("ÆØÅ".to_lowercase_cow(), "æøå");
+        assert!(matches!("ÆØÅ".to_uppercase_cow(), Cow::Borrowed(_)));
+        // 'ß' uppercases to two characters, so it must not be borrowed
+        assert_eq!("straße".to_uppercase_cow(), "STRASSE");
+    }
+
+    #[test]
+    fn dedent_only_removes_the_common_margin() {
+        assert_eq!("  a\n    b\n  c".dedent(), "a\n  b\nc");
+        assert_eq!("\ta\n  b\n".dedent(), "\ta\n  b\n");
+        assert_eq!("    a\n   \n    b".dedent(), "a\n\nb");
+        assert!(matches!("a\n\n b\n".dedent(), Cow::Borrowed(_)));
+        assert!(matches!("a\n \nb".dedent(), Cow::Owned(_)));
+        assert_eq!("".dedent(), "");
+    }
+
+    #[test]
+    fn indent_skips_blank_lines() {
+        assert_eq!("a\r\n \r\nb".indent("> "), "> a\r\n \r\n> b");
+        assert_eq!("".indent("> "), "");
+    }
+
+    #[test]
+    fn replace_all_prefers_left_most_longest_matches() {
+        let replacements = [("ab", "1"), ("abc", "2"), ("bc", "3"), ("", "4")];
+        assert_eq!("abcabxbc".replace_all(&replacements), "21x3");
+        assert_eq!("æøå".replace_all(&[("ø", "o")]), "æoå");
+        assert!(matches!("xyz".replace_all(&replacements), Cow::Borrowed(_)));
+        assert!(matches!("".replace_all(&[]), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn whitespace_normalization() {
+        assert!("".is_blank());
+        assert!(" \t\u{3000}\n".is_blank());
+        assert!(!" . ".is_blank());
+
+        assert!(matches!("a b c".normalize_whitespace(), Cow::Borrowed(_)));
+        assert_eq!(" a".normalize_whitespace(), "a");
+        assert_eq!("a\u{a0}b".normalize_whitespace(), "a b");
+        assert_eq!(" \n ".normalize_whitespace(), "");
+    }
+
+    #[test]
+    fn substring_chars_checks_the_range() {
+        assert_eq!("æøå".substring_chars(..), Ok("æøå"));
+        assert_eq!("æøå".substring_chars(1..=2), Ok("øå"));
+        assert_eq!("æøå".substring_chars(3..), Ok(""));
+        assert_eq!("æøå".substring_chars(1..1), Ok(""));
+        assert_eq!(
+            "æøå".substring_chars((Bound::Included(2), Bound::Excluded(1))),
+            Err(CharRangeError::StartAfterEnd { start: 2, end: 1 })
+        );
+        assert_eq!(
+            "æøå".substring_chars(4..),
+            Err(CharRangeError::OutOfBounds { index: 4, len: 3 })
+        );
+        assert_eq!(
+            "æøå".substring_chars(1..=usize::MAX),
+            Err(CharRangeError::OutOfBounds {
+                index: usize::MAX,
+                len: 3
+            })
+        );
+    }
+
+    #[test]
+    fn trimming_never_changes_ownership() {
+        let owned = Cow::<str>::Owned(String::from("--ab--")).trim_matches_cow('-');
+        assert!(matches!(&owned, Cow::Owned(s) if s == "ab"));
+
+        let borrowed = Cow::Borrowed(" \tab\n").trim_cow();
+        assert!(matches!(borrowed, Cow::Borrowed("ab")));
+
+        let repeated = Cow::Borrowed("ababc").trim_start_matches_cow("ab");
+        assert_eq!(repeated, "c");
+        let any = Cow::Borrowed("xyzyx").trim_end_matches_cow(&['x', 'y'][..]);
+        assert_eq!(any, "xyz");
+        let all = Cow::Borrowed("aaa").trim_matches_cow(|c| c == 'a');
+        assert_eq!(all, "");
+    }
 }
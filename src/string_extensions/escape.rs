@@ -0,0 +1,164 @@
+use super::StrExtensions;
+use std::borrow::Cow;
+
+pub(super) fn escape_html(s: &str) -> Cow<'_, str> {
+    s.replace_all(&[
+        ("&", "&amp;"),
+        ("<", "&lt;"),
+        (">", "&gt;"),
+        ("\"", "&quot;"),
+        ("'", "&#39;"),
+    ])
+}
+
+pub(super) fn unescape_html(s: &str) -> Cow<'_, str> {
+    let mut unescaped = String::new();
+    // The start of the text that has yet to be copied into `unescaped`
+    let mut copied = 0;
+    let mut search_from = 0;
+    while let Some(offset) = s[search_from..].find('&') {
+        let start = search_from + offset;
+        search_from = start + 1;
+
+        let entity = s[start + 1..]
+            .find(';')
+            .map(|len| &s[start + 1..start + 1 + len]);
+        if let Some((entity, c)) = entity.and_then(|e| decode_entity(e).map(|c| (e, c))) {
+            unescaped.push_str(&s[copied..start]);
+            unescaped.push(c);
+            copied = start + entity.len() + 2;
+            search_from = copied;
+        }
+    }
+
+    if copied == 0 {
+        Cow::Borrowed(s)
+    } else {
+        unescaped.push_str(&s[copied..]);
+        Cow::Owned(unescaped)
+    }
+}
+
+/// Decodes the name of an HTML entity, i.e., what is between the `&` and the `;`.
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let number = entity.strip_prefix('#')?;
+            let (digits, radix) = match number.strip_prefix(&['x', 'X'][..]) {
+                Some(hex) => (hex, 16),
+                None => (number, 10),
+            };
+            // `from_str_radix` would also accept a leading `+`.
+            if !digits.chars().all(|c| c.is_digit(radix)) {
+                return None;
+            }
+            u32::from_str_radix(digits, radix)
+                .ok()
+                .and_then(char::from_u32)
+        }
+    }
+}
+
+/// Whether `c` can appear unquoted in a POSIX shell word without any special meaning.
+fn is_shell_safe(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "_-+=@%:,./".contains(c)
+}
+
+pub(super) fn escape_shell_arg(s: &str) -> Cow<'_, str> {
+    if !s.is_empty() && s.chars().all(is_shell_safe) {
+        return Cow::Borrowed(s);
+    }
+
+    Cow::Owned(format!("'{}'", s.replace('\'', r"'\''")))
+}
+
+pub(super) fn unescape_shell_arg(s: &str) -> Option<Cow<'_, str>> {
+    if s.chars().all(is_shell_safe) {
+        return Some(Cow::Borrowed(s));
+    }
+
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => loop {
+                match chars.next()? {
+                    '\'' => break,
+                    quoted => unescaped.push(quoted),
+                }
+            },
+            '"' => loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => match chars.next()? {
+                        escaped @ ('$' | '`' | '"' | '\\') => unescaped.push(escaped),
+                        '\n' => {}
+                        other => {
+                            unescaped.push('\\');
+                            unescaped.push(other);
+                        }
+                    },
+                    quoted => unescaped.push(quoted),
+                }
+            },
+            '\\' => match chars.next()? {
+                '\n' => {}
+                escaped => unescaped.push(escaped),
+            },
+            _ => unescaped.push(c),
+        }
+    }
+
+    Some(Cow::Owned(unescaped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_round_trips() {
+        let text = r#"<a href="x">Tom & Jerry's</a>"#;
+        let escaped = escape_html(text);
+        assert_eq!(
+            escaped,
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+        assert_eq!(unescape_html(&escaped), text);
+
+        assert!(matches!(escape_html("plain"), Cow::Borrowed(_)));
+        assert!(matches!(unescape_html("a & b;"), Cow::Borrowed(_)));
+        assert_eq!(
+            unescape_html("&#x41;&#66;&bogus;&amp;lt;&"),
+            "AB&bogus;&lt;&"
+        );
+    }
+
+    #[test]
+    fn signed_numeric_entities_are_not_decoded() {
+        assert_eq!(unescape_html("&#x+41;&#+65;"), "&#x+41;&#+65;");
+        assert!(matches!(unescape_html("&#+65;"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn shell_args_round_trip() {
+        for arg in ["", "simple.txt", "two words", "it's", "$HOME", "'''"] {
+            assert_eq!(unescape_shell_arg(&escape_shell_arg(arg)).unwrap(), arg);
+        }
+
+        assert!(matches!(escape_shell_arg("a/b.c"), Cow::Borrowed(_)));
+        assert_eq!(escape_shell_arg(""), "''");
+        assert_eq!(escape_shell_arg("it's"), r"'it'\''s'");
+        assert_eq!(
+            unescape_shell_arg(r#"a\ b"\$c\d"'e'"#).unwrap(),
+            r"a b$c\de"
+        );
+        assert_eq!(unescape_shell_arg("'open"), None);
+    }
+}
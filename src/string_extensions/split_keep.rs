@@ -0,0 +1,213 @@
+use std::iter::FusedIterator;
+
+/// A pattern that [`split_keep`](super::StrExtensions::split_keep) can split a [`str`] on, and that
+/// the `trim_*_matches_cow` methods of [`CowStrExtensions`](super::CowStrExtensions) can trim.
+///
+/// This is a stand-in for the standard library's unstable `Pattern` trait, and is implemented
+/// for the same kinds of patterns: a [`char`], a [`str`], a slice of [`char`]s matching any one
+/// of them, and a closure deciding whether a [`char`] matches.
+pub trait SplitPattern {
+    /// Returns the byte range of the first match of this pattern in `haystack`, if any.
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the length in bytes of the match of this pattern at the very start of `haystack`,
+    /// if there is one.
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize>;
+
+    /// Returns the length in bytes of the match of this pattern at the very end of `haystack`,
+    /// if there is one.
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize>;
+}
+
+impl SplitPattern for char {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(*self).map(|i| (i, i + self.len_utf8()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then(|| self.len_utf8())
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then(|| self.len_utf8())
+    }
+}
+
+/// An empty [`str`] never matches.
+impl SplitPattern for &str {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        haystack.find(*self).map(|i| (i, i + self.len()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        (!self.is_empty() && haystack.starts_with(*self)).then_some(self.len())
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        (!self.is_empty() && haystack.ends_with(*self)).then_some(self.len())
+    }
+}
+
+impl SplitPattern for &[char] {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .find(|(_, c)| self.contains(c))
+            .map(|(i, c)| (i, i + c.len_utf8()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        let mut pattern = |c| self.contains(&c);
+        pattern.prefix_len(haystack)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        let mut pattern = |c| self.contains(&c);
+        pattern.suffix_len(haystack)
+    }
+}
+
+impl<F: FnMut(char) -> bool> SplitPattern for F {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .find(|&(_, c)| self(c))
+            .map(|(i, c)| (i, i + c.len_utf8()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next()
+            .filter(|&c| self(c))
+            .map(char::len_utf8)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next_back()
+            .filter(|&c| self(c))
+            .map(char::len_utf8)
+    }
+}
+
+/// A part of a [`str`] split by [`split_keep`](super::StrExtensions::split_keep).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SplitPart<'a> {
+    /// The text between two delimiters.
+    Text(&'a str),
+    /// A match of the pattern that was split on.
+    Delimiter(&'a str),
+}
+
+impl<'a> SplitPart<'a> {
+    /// Returns the text of this part, regardless of whether it's a delimiter or not.
+    #[must_use]
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            Self::Text(s) | Self::Delimiter(s) => s,
+        }
+    }
+}
+
+/// An iterator over the parts of a [`str`] separated by a pattern, including the matches of the
+/// pattern themselves.
+///
+/// This `struct` is created by the [`split_keep`](super::StrExtensions::split_keep) method on
+/// [`StrExtensions`](super::StrExtensions).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct SplitKeep<'a, P> {
+    remainder: &'a str,
+    pattern: P,
+    delimiter: Option<&'a str>,
+}
+
+impl<'a, P> SplitKeep<'a, P> {
+    pub(super) fn new(text: &'a str, pattern: P) -> Self {
+        Self {
+            remainder: text,
+            pattern,
+            delimiter: None,
+        }
+    }
+}
+
+impl<'a, P: SplitPattern> Iterator for SplitKeep<'a, P> {
+    type Item = SplitPart<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(delimiter) = self.delimiter.take() {
+            return Some(SplitPart::Delimiter(delimiter));
+        }
+        if self.remainder.is_empty() {
+            return None;
+        }
+
+        if let Some((start, end)) = self.pattern.find_in(self.remainder) {
+            let text = &self.remainder[..start];
+            let delimiter = &self.remainder[start..end];
+            self.remainder = &self.remainder[end..];
+            if text.is_empty() {
+                return Some(SplitPart::Delimiter(delimiter));
+            }
+            self.delimiter = Some(delimiter);
+            Some(SplitPart::Text(text))
+        } else {
+            let text = self.remainder;
+            self.remainder = "";
+            Some(SplitPart::Text(text))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.delimiter.is_some());
+        let rest = usize::from(!self.remainder.is_empty());
+        (pending + rest, Some(pending + self.remainder.len()))
+    }
+}
+
+impl<P: SplitPattern> FusedIterator for SplitKeep<'_, P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_delimiters_between_text() {
+        let parts: Vec<_> = SplitKeep::new("a+b--c", &['+', '-'][..]).collect();
+        assert_eq!(
+            parts,
+            [
+                SplitPart::Text("a"),
+                SplitPart::Delimiter("+"),
+                SplitPart::Text("b"),
+                SplitPart::Delimiter("-"),
+                SplitPart::Delimiter("-"),
+                SplitPart::Text("c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_edges_and_multi_byte_patterns() {
+        let parts: Vec<_> = SplitKeep::new("::ø::", "::").collect();
+        assert_eq!(
+            parts,
+            [
+                SplitPart::Delimiter("::"),
+                SplitPart::Text("ø"),
+                SplitPart::Delimiter("::"),
+            ]
+        );
+
+        assert_eq!(SplitKeep::new("", 'a').next(), None);
+        let unsplit: Vec<_> = SplitKeep::new("abc", "").map(|p| p.as_str()).collect();
+        assert_eq!(unsplit, ["abc"]);
+    }
+}
@@ -0,0 +1,208 @@
+use super::StrExtensions;
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+
+/// Helper struct for wrapping text at word boundaries so that no line is wider than a given
+/// number of characters. With the `unicode` feature enabled, widths are measured in terminal
+/// columns instead, so that wide characters like CJK ideographs and emoji count as two.
+///
+/// This `struct` is created by the [`wrap`](super::StrExtensions::wrap) method on
+/// [`StrExtensions`](super::StrExtensions).
+#[derive(Debug, Clone, Copy)]
+pub struct Wrap<'a> {
+    text: &'a str,
+    width: usize,
+    break_long_words: bool,
+    preserve_newlines: bool,
+}
+
+impl<'a> Wrap<'a> {
+    pub(super) fn new(text: &'a str, width: usize) -> Self {
+        assert!(width > 0, "The width must be greater than zero.");
+
+        Self {
+            text,
+            width,
+            break_long_words: false,
+            preserve_newlines: true,
+        }
+    }
+
+    /// Sets whether words wider than the width get broken across several lines. When disabled,
+    /// such words are put on a line of their own, which will then be wider than the width.
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn break_long_words(mut self, break_long_words: bool) -> Self {
+        self.break_long_words = break_long_words;
+        self
+    }
+
+    /// Sets whether the line breaks already in the text are kept. When disabled, line breaks are
+    /// treated like any other whitespace, so the text gets wrapped as a single paragraph.
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn preserve_newlines(mut self, preserve_newlines: bool) -> Self {
+        self.preserve_newlines = preserve_newlines;
+        self
+    }
+
+    /// Returns the wrapped lines. Words on the same line are separated by a single space; lines
+    /// are borrowed from the original text whenever it already looks that way.
+    #[must_use]
+    pub fn lines(&self) -> Vec<Cow<'a, str>> {
+        let mut lines = Vec::new();
+        if self.preserve_newlines {
+            for paragraph in self.text.lines() {
+                let before = lines.len();
+                self.wrap_paragraph(paragraph, &mut lines);
+                if lines.len() == before {
+                    lines.push(Cow::Borrowed(""));
+                }
+            }
+        } else {
+            self.wrap_paragraph(self.text, &mut lines);
+        }
+
+        lines
+    }
+
+    fn wrap_paragraph(&self, paragraph: &'a str, lines: &mut Vec<Cow<'a, str>>) {
+        // The current line, as a byte range into `paragraph` and its width
+        let mut current: Option<(usize, usize, usize)> = None;
+        for word in paragraph.split_whitespace() {
+            let start = word.as_ptr() as usize - paragraph.as_ptr() as usize;
+            let end = start + word.len();
+            let word_width = text_width(word);
+
+            if let Some((line_start, line_end, line_width)) = &mut current {
+                if *line_width + 1 + word_width <= self.width {
+                    *line_end = end;
+                    *line_width += 1 + word_width;
+                    continue;
+                }
+                lines.push(paragraph[*line_start..*line_end].normalize_whitespace());
+            }
+
+            if self.break_long_words && word_width > self.width {
+                let mut pieces = split_every(word, self.width);
+                let last = pieces.pop().expect("a word is never empty");
+                lines.extend(pieces.into_iter().map(Cow::Borrowed));
+
+                let last_start = end - last.len();
+                current = Some((last_start, end, text_width(last)));
+            } else {
+                current = Some((start, end, word_width));
+            }
+        }
+
+        if let Some((line_start, line_end, _)) = current {
+            lines.push(paragraph[line_start..line_end].normalize_whitespace());
+        }
+    }
+}
+
+/// Returns the width of `text`, in characters or, with the `unicode` feature, in columns.
+fn text_width(text: &str) -> usize {
+    #[cfg(feature = "unicode")]
+    {
+        text.display_width()
+    }
+    #[cfg(not(feature = "unicode"))]
+    {
+        text.chars().count()
+    }
+}
+
+/// Returns the width of `c`, in characters or, with the `unicode` feature, in columns.
+fn char_width(c: char) -> usize {
+    #[cfg(feature = "unicode")]
+    {
+        unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+    }
+    #[cfg(not(feature = "unicode"))]
+    {
+        let _ = c;
+        1
+    }
+}
+
+/// Splits `word` into pieces at most `width` wide, except for when a single character is wider
+/// than that. Only the last piece may be narrower than `width`.
+fn split_every(word: &str, width: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut piece_width = 0;
+    for (index, c) in word.char_indices() {
+        let c_width = char_width(c);
+        if index > start && piece_width + c_width > width {
+            pieces.push(&word[start..index]);
+            start = index;
+            piece_width = 0;
+        }
+        piece_width += c_width;
+    }
+    pieces.push(&word[start..]);
+
+    pieces
+}
+
+/// Writes the wrapped lines separated by `'\n'`.
+impl Display for Wrap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, line) in self.lines().iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str(line)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breaks_long_words_only_when_asked() {
+        let text = "a abcdef b";
+        assert_eq!(Wrap::new(text, 4).lines(), ["a", "abcdef", "b"]);
+        assert_eq!(
+            Wrap::new(text, 4).break_long_words(true).lines(),
+            ["a", "abcd", "ef b"]
+        );
+        assert_eq!(
+            Wrap::new("ææææ", 2).break_long_words(true).lines(),
+            ["ææ", "ææ"]
+        );
+    }
+
+    #[test]
+    fn handles_newlines_and_whitespace() {
+        let text = "one  two\n\nthree\tfour";
+        assert_eq!(Wrap::new(text, 20).lines(), ["one two", "", "three four"]);
+        assert_eq!(
+            Wrap::new(text, 20).preserve_newlines(false).lines(),
+            ["one two three four"]
+        );
+        assert!(Wrap::new("", 5).lines().is_empty());
+        assert!(matches!(Wrap::new("a b", 5).lines()[0], Cow::Borrowed(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn measures_wide_characters_in_columns() {
+        assert_eq!(Wrap::new("日本 語", 4).lines(), ["日本", "語"]);
+        assert_eq!(
+            Wrap::new("日本語", 4).break_long_words(true).lines(),
+            ["日本", "語"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than zero")]
+    fn zero_width_panics() {
+        let _ = Wrap::new("text", 0);
+    }
+}
@@ -0,0 +1,60 @@
+//! Round-trip tests against a real serialization format. These live outside the library's unit
+//! tests so that `serde_json`'s extra `PartialEq` impls don't interfere with type inference there.
+#![cfg(feature = "serde")]
+
+use ilyvion_util::color::{self, Hsv, Rgb};
+use ilyvion_util::non_nan::{Finite, NonNan};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Colors {
+    plain: Rgb,
+    #[serde(with = "color::hex")]
+    hex: Rgb,
+    hsv: Hsv,
+}
+
+#[test]
+fn colors_round_trip_both_representations() {
+    let colors = Colors {
+        plain: Rgb::new(1, 2, 3),
+        hex: Rgb::new(0xab, 0xcd, 0xef),
+        hsv: Hsv::new(120., 0.5, 1.),
+    };
+
+    let json = serde_json::to_string(&colors).unwrap();
+    assert_eq!(
+        json,
+        r##"{"plain":{"red":1,"green":2,"blue":3},"hex":"#abcdef","hsv":{"hue":120.0,"saturation":0.5,"value":1.0}}"##
+    );
+    assert_eq!(serde_json::from_str::<Colors>(&json).unwrap(), colors);
+}
+
+#[test]
+fn color_hex_accepts_uppercase_and_missing_hash() {
+    #[derive(Deserialize)]
+    struct Hex(#[serde(with = "color::hex")] Rgb);
+
+    let Hex(rgb) = serde_json::from_str(r#""ABCDEF""#).unwrap();
+    assert_eq!(rgb, Rgb::new(0xab, 0xcd, 0xef));
+
+    assert!(serde_json::from_str::<Hex>(r##""#abcde""##).is_err());
+    assert!(serde_json::from_str::<Hex>(r##""#abcdeg""##).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    ratio: NonNan<f64>,
+    scale: Finite<f32>,
+}
+
+#[test]
+fn non_nan_serializes_transparently() {
+    let config = Config {
+        ratio: NonNan::new(0.5),
+        scale: Finite::new(2.0),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"ratio":0.5,"scale":2.0}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+}